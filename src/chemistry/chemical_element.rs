@@ -369,4 +369,285 @@ impl ChemicalElement {
             Self::Oganesson => "Og",
         }
     }
+
+    /// get common oxidation states of chemical element, most common first
+    pub fn oxidation_states(&self) -> &'static [i32] {
+        match self {
+            Self::Hydrogen => &[1, -1],
+            Self::Helium => &[],
+            Self::Lithium => &[1],
+            Self::Beryllium => &[2],
+            Self::Boron => &[3],
+            Self::Carbon => &[4, -4, 2, -2, -3, -1, 1, 3],
+            Self::Nitrogen => &[-3, 3, 5, 4, 2, 1, -1, -2],
+            Self::Oxygen => &[-2, -1, 2],
+            Self::Fluorine => &[-1],
+            Self::Neon => &[],
+            Self::Sodium => &[1],
+            Self::Magnesium => &[2],
+            Self::Aluminium => &[3],
+            Self::Silicon => &[4, -4],
+            Self::Phosphorus => &[5, 3, -3],
+            Self::Sulfur => &[-2, 6, 4, 2],
+            Self::Chlorine => &[-1, 1, 3, 5, 7],
+            Self::Argon => &[],
+            Self::Potassium => &[1],
+            Self::Calcium => &[2],
+            Self::Scandium => &[3],
+            Self::Titanium => &[4, 3, 2],
+            Self::Vanadium => &[5, 4, 3, 2],
+            Self::Chromium => &[3, 6, 2],
+            Self::Manganese => &[2, 4, 7, 3, 6],
+            Self::Iron => &[3, 2],
+            Self::Cobalt => &[2, 3],
+            Self::Nickel => &[2, 3],
+            Self::Copper => &[2, 1],
+            Self::Zinc => &[2],
+            Self::Gallium => &[3],
+            Self::Germanium => &[4, 2, -4],
+            Self::Arsenic => &[3, 5, -3],
+            Self::Selenium => &[-2, 4, 6],
+            Self::Bromine => &[-1, 1, 3, 5],
+            Self::Krypton => &[2],
+            Self::Rubidium => &[1],
+            Self::Strontium => &[2],
+            Self::Yttrium => &[3],
+            Self::Zirconium => &[4],
+            Self::Niobium => &[5, 3],
+            Self::Molybdenum => &[6, 4, 3, 2],
+            Self::Technetium => &[7, 4],
+            Self::Ruthenium => &[3, 4, 2, 8],
+            Self::Rhodium => &[3, 1],
+            Self::Palladium => &[2, 4],
+            Self::Silver => &[1],
+            Self::Cadmium => &[2],
+            Self::Indium => &[3, 1],
+            Self::Tin => &[4, 2, -4],
+            Self::Antimony => &[3, 5, -3],
+            Self::Tellurium => &[-2, 4, 6],
+            Self::Iodine => &[-1, 1, 5, 7],
+            Self::Xenon => &[2, 4, 6],
+            Self::Caesium => &[1],
+            Self::Barium => &[2],
+            Self::Lanthanum => &[3],
+            Self::Cerium => &[3, 4],
+            Self::Praseodymium => &[3],
+            Self::Neodymium => &[3],
+            Self::Promethium => &[3],
+            Self::Samarium => &[3, 2],
+            Self::Europium => &[3, 2],
+            Self::Gadolinium => &[3],
+            Self::Terbium => &[3],
+            Self::Dysprosium => &[3],
+            Self::Holmium => &[3],
+            Self::Erbium => &[3],
+            Self::Thulium => &[3],
+            Self::Ytterbium => &[3, 2],
+            Self::Lutetium => &[3],
+            Self::Hafnium => &[4],
+            Self::Tantalum => &[5],
+            Self::Tungsten => &[6, 4],
+            Self::Rhenium => &[4, 7],
+            Self::Osmium => &[4, 8],
+            Self::Iridium => &[3, 4],
+            Self::Platinum => &[2, 4],
+            Self::Gold => &[3, 1],
+            Self::Mercury => &[2, 1],
+            Self::Thallium => &[1, 3],
+            Self::Lead => &[2, 4],
+            Self::Bismuth => &[3],
+            Self::Polonium => &[4, 2, -2],
+            Self::Astatine => &[-1, 1],
+            Self::Radon => &[2],
+            Self::Francium => &[1],
+            Self::Radium => &[2],
+            Self::Actinium => &[3],
+            Self::Thorium => &[4],
+            Self::Protactinium => &[5],
+            Self::Uranium => &[6, 4],
+            Self::Neptunium => &[5],
+            Self::Plutonium => &[4],
+            Self::Americium => &[3],
+            Self::Curium => &[3],
+            Self::Berkelium => &[3],
+            Self::Californium => &[3],
+            Self::Einsteinium => &[3],
+            Self::Fermium => &[3],
+            Self::Mendelevium => &[3],
+            Self::Nobelium => &[2],
+            Self::Lawrencium => &[3],
+            Self::Rutherfordium => &[4],
+            Self::Dubnium => &[5],
+            Self::Seaborgium => &[6],
+            Self::Bohrium => &[7],
+            Self::Hassium => &[8],
+            Self::Meitnerium => &[],
+            Self::Darmstadtium => &[],
+            Self::Roentgenium => &[],
+            Self::Copernicium => &[2],
+            Self::Nihonium => &[],
+            Self::Flerovium => &[2],
+            Self::Moscovium => &[],
+            Self::Livermorium => &[],
+            Self::Tennessine => &[],
+            Self::Oganesson => &[],
+        }
+    }
+
+    /// get chemical element electronegativity on Pauling scale
+    pub fn electronegativity(&self) -> Option<f32> {
+        match self {
+            Self::Hydrogen => Some(2.20),
+            Self::Helium => None,
+            Self::Lithium => Some(0.98),
+            Self::Beryllium => Some(1.57),
+            Self::Boron => Some(2.04),
+            Self::Carbon => Some(2.55),
+            Self::Nitrogen => Some(3.04),
+            Self::Oxygen => Some(3.44),
+            Self::Fluorine => Some(3.98),
+            Self::Neon => None,
+            Self::Sodium => Some(0.93),
+            Self::Magnesium => Some(1.31),
+            Self::Aluminium => Some(1.61),
+            Self::Silicon => Some(1.90),
+            Self::Phosphorus => Some(2.19),
+            Self::Sulfur => Some(2.58),
+            Self::Chlorine => Some(3.16),
+            Self::Argon => None,
+            Self::Potassium => Some(0.82),
+            Self::Calcium => Some(1.00),
+            Self::Scandium => Some(1.36),
+            Self::Titanium => Some(1.54),
+            Self::Vanadium => Some(1.63),
+            Self::Chromium => Some(1.66),
+            Self::Manganese => Some(1.55),
+            Self::Iron => Some(1.83),
+            Self::Cobalt => Some(1.88),
+            Self::Nickel => Some(1.91),
+            Self::Copper => Some(1.90),
+            Self::Zinc => Some(1.65),
+            Self::Gallium => Some(1.81),
+            Self::Germanium => Some(2.01),
+            Self::Arsenic => Some(2.18),
+            Self::Selenium => Some(2.55),
+            Self::Bromine => Some(2.96),
+            Self::Krypton => Some(3.00),
+            Self::Rubidium => Some(0.82),
+            Self::Strontium => Some(0.95),
+            Self::Yttrium => Some(1.22),
+            Self::Zirconium => Some(1.33),
+            Self::Niobium => Some(1.6),
+            Self::Molybdenum => Some(2.16),
+            Self::Technetium => Some(1.9),
+            Self::Ruthenium => Some(2.2),
+            Self::Rhodium => Some(2.28),
+            Self::Palladium => Some(2.20),
+            Self::Silver => Some(1.93),
+            Self::Cadmium => Some(1.69),
+            Self::Indium => Some(1.78),
+            Self::Tin => Some(1.96),
+            Self::Antimony => Some(2.05),
+            Self::Tellurium => Some(2.1),
+            Self::Iodine => Some(2.66),
+            Self::Xenon => Some(2.6),
+            Self::Caesium => Some(0.79),
+            Self::Barium => Some(0.89),
+            Self::Lanthanum => Some(1.10),
+            Self::Cerium => Some(1.12),
+            Self::Praseodymium => Some(1.13),
+            Self::Neodymium => Some(1.14),
+            Self::Promethium => Some(1.13),
+            Self::Samarium => Some(1.17),
+            Self::Europium => Some(1.2),
+            Self::Gadolinium => Some(1.2),
+            Self::Terbium => Some(1.1),
+            Self::Dysprosium => Some(1.22),
+            Self::Holmium => Some(1.23),
+            Self::Erbium => Some(1.24),
+            Self::Thulium => Some(1.25),
+            Self::Ytterbium => Some(1.1),
+            Self::Lutetium => Some(1.27),
+            Self::Hafnium => Some(1.3),
+            Self::Tantalum => Some(1.5),
+            Self::Tungsten => Some(2.36),
+            Self::Rhenium => Some(1.9),
+            Self::Osmium => Some(2.2),
+            Self::Iridium => Some(2.20),
+            Self::Platinum => Some(2.28),
+            Self::Gold => Some(2.54),
+            Self::Mercury => Some(2.00),
+            Self::Thallium => Some(1.62),
+            Self::Lead => Some(2.33),
+            Self::Bismuth => Some(2.02),
+            Self::Polonium => Some(2.0),
+            Self::Astatine => Some(2.2),
+            Self::Radon => Some(2.2),
+            Self::Francium => Some(0.7),
+            Self::Radium => Some(0.9),
+            Self::Actinium => Some(1.1),
+            Self::Thorium => Some(1.3),
+            Self::Protactinium => Some(1.5),
+            Self::Uranium => Some(1.38),
+            Self::Neptunium => Some(1.36),
+            Self::Plutonium => Some(1.28),
+            Self::Americium => Some(1.3),
+            Self::Curium => Some(1.28),
+            Self::Berkelium => Some(1.3),
+            Self::Californium => Some(1.3),
+            Self::Einsteinium => Some(1.3),
+            Self::Fermium => Some(1.3),
+            Self::Mendelevium => Some(1.3),
+            Self::Nobelium => Some(1.3),
+            Self::Lawrencium => Some(1.3),
+            Self::Rutherfordium => None,
+            Self::Dubnium => None,
+            Self::Seaborgium => None,
+            Self::Bohrium => None,
+            Self::Hassium => None,
+            Self::Meitnerium => None,
+            Self::Darmstadtium => None,
+            Self::Roentgenium => None,
+            Self::Copernicium => None,
+            Self::Nihonium => None,
+            Self::Flerovium => None,
+            Self::Moscovium => None,
+            Self::Livermorium => None,
+            Self::Tennessine => None,
+            Self::Oganesson => None,
+        }
+    }
+
+    /// check if chemical element is metal, metalloids are not counted
+    pub fn is_metal(&self) -> bool {
+        !matches!(
+            self,
+            Self::Hydrogen
+                | Self::Helium
+                | Self::Boron
+                | Self::Carbon
+                | Self::Nitrogen
+                | Self::Oxygen
+                | Self::Fluorine
+                | Self::Neon
+                | Self::Silicon
+                | Self::Phosphorus
+                | Self::Sulfur
+                | Self::Chlorine
+                | Self::Argon
+                | Self::Germanium
+                | Self::Arsenic
+                | Self::Selenium
+                | Self::Bromine
+                | Self::Krypton
+                | Self::Antimony
+                | Self::Tellurium
+                | Self::Iodine
+                | Self::Xenon
+                | Self::Astatine
+                | Self::Radon
+                | Self::Tennessine
+                | Self::Oganesson
+        )
+    }
 }
@@ -8,7 +8,7 @@ pub struct Table<'a> {
 
 impl<'a> Table<'a> {
     pub fn new() -> Self {
-        let table = Self {
+        Self {
             elements: HashMap::from([
                 (Element::Hydrogen.symbol(), Element::Hydrogen),
                 (Element::Helium.symbol(), Element::Helium),
@@ -129,15 +129,10 @@ impl<'a> Table<'a> {
                 (Element::Tennessine.symbol(), Element::Tennessine),
                 (Element::Oganesson.symbol(), Element::Oganesson),
             ]),
-        };
-
-        table
+        }
     }
 
     pub fn lookup(&self, symbol: &str) -> Option<Element> {
-        match self.elements.get(symbol) {
-            Some(element) => Some(*element),
-            None => None,
-        }
+        self.elements.get(symbol).copied()
     }
 }
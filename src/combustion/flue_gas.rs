@@ -0,0 +1,52 @@
+/// A flue gas of burnt fuel
+///
+/// Contains moles of each gas per kilogram of fuel
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlueGas {
+    components: Vec<(String, f32)>,
+}
+
+impl FlueGas {
+    pub(crate) fn new() -> Self {
+        Self { components: vec![] }
+    }
+
+    pub(crate) fn add(&mut self, formula: &str, moles: f32) {
+        match self
+            .components
+            .iter_mut()
+            .find(|(added, _)| added == formula)
+        {
+            Some((_, added)) => *added += moles,
+            None => self.components.push((formula.to_string(), moles)),
+        }
+    }
+
+    /// list gases with mol per kg of fuel
+    pub fn components(&self) -> &[(String, f32)] {
+        &self.components
+    }
+
+    /// get total mol of flue gas per kg of fuel
+    pub fn moles(&self) -> f32 {
+        self.components.iter().map(|(_, moles)| moles).sum()
+    }
+
+    /// get mole, or volume, fraction of gas in wet flue gas
+    pub fn fraction(&self, formula: &str) -> f32 {
+        self.moles_of(formula) / self.moles()
+    }
+
+    /// get mole, or volume, fraction of gas in flue gas without water vapour
+    pub fn dry_fraction(&self, formula: &str) -> f32 {
+        self.moles_of(formula) / (self.moles() - self.moles_of("H2O"))
+    }
+
+    fn moles_of(&self, formula: &str) -> f32 {
+        self.components
+            .iter()
+            .find(|(added, _)| added == formula)
+            .map(|(_, moles)| *moles)
+            .unwrap_or(0.0)
+    }
+}
@@ -0,0 +1,188 @@
+use crate::combustion::FlueGas;
+use crate::{Compound, Error, Fraction, Mixture, PERIODIC_TABLE};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+/// dry air by volume
+static AIR: Lazy<Mixture> = Lazy::new(|| {
+    let mut air = Mixture::new(Fraction::Volume);
+
+    air.add("N2", 78.08).unwrap();
+    air.add("O2", 20.95).unwrap();
+    air.add("Ar", 0.93).unwrap();
+
+    air
+});
+
+/// A fuel defined by formula or by mass composition
+///
+/// Carbon, hydrogen and sulfur burn to CO2, H2O and SO2, nitrogen is
+/// released as N2 and oxygen of fuel reduces demand of air. Amounts are
+/// given per kilogram of fuel
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fuel {
+    elements: HashMap<&'static str, f32>,
+}
+
+impl Fuel {
+    /// create fuel of compound defined by formula
+    pub fn from_formula(formula: &str) -> Result<Self, Error> {
+        Ok(Self::from(&crate::parse(formula)?))
+    }
+
+    /// create fuel of mass percents of elements, like CHNOS analysis,
+    /// the rest is taken as ash and moisture
+    pub fn from_composition(composition: &[(&str, f32)]) -> Result<Self, Error> {
+        let mut elements = HashMap::new();
+
+        for (symbol, percent) in composition {
+            let chemical_element = PERIODIC_TABLE
+                .lookup(symbol)
+                .ok_or(Error::UnknownSymbol(symbol.to_string()))?;
+
+            *elements.entry(chemical_element.symbol()).or_insert(0.0) += percent;
+        }
+
+        Ok(Self { elements })
+    }
+
+    /// list percents of element mass to fuel mass
+    pub fn elements(&self) -> &HashMap<&'static str, f32> {
+        &self.elements
+    }
+
+    /// get mol of O2 burning kilogram of fuel completely
+    pub fn oxygen_moles(&self) -> f32 {
+        self.moles("C") + self.moles("H") / 4. + self.moles("S") - self.moles("O") / 2.
+    }
+
+    /// get kilograms of O2 burning kilogram of fuel completely
+    pub fn stoichiometric_oxygen(&self) -> f32 {
+        self.oxygen_moles() * crate::parse("O2").unwrap().molar_mass() / 1000.
+    }
+
+    /// get kilograms of dry air burning kilogram of fuel completely
+    pub fn stoichiometric_air(&self) -> f32 {
+        self.air_moles(0.) * AIR.molar_mass() / 1000.
+    }
+
+    /// get mass ratio of air to fuel at excess air in percent
+    pub fn air_fuel_ratio(&self, excess_air: f32) -> f32 {
+        self.stoichiometric_air() * (1. + excess_air / 100.)
+    }
+
+    /// get flue gas of kilogram of fuel burnt at excess air in percent
+    pub fn flue_gas(&self, excess_air: f32) -> FlueGas {
+        let mut flue_gas = FlueGas::new();
+
+        flue_gas.add("CO2", self.moles("C"));
+        flue_gas.add("H2O", self.moles("H") / 2.);
+        flue_gas.add("SO2", self.moles("S"));
+        flue_gas.add("N2", self.moles("N") / 2.);
+
+        let air_moles = self.air_moles(excess_air);
+
+        AIR.parts()
+            .iter()
+            .zip(AIR.mole_fractions())
+            .for_each(|((compound, _), fraction)| {
+                let formula = compound.substance().to_string();
+
+                match formula.as_str() {
+                    // oxygen left unburnt
+                    "O2" => flue_gas.add("O2", self.oxygen_moles() * excess_air / 100.),
+                    _ => flue_gas.add(&formula, air_moles * fraction),
+                }
+            });
+
+        flue_gas
+    }
+
+    /// estimate higher heating value in MJ/kg by Dulong's formula
+    pub fn heating_value(&self) -> f32 {
+        let fraction = |symbol| self.elements.get(symbol).copied().unwrap_or(0.0) / 100.;
+
+        33.823 * fraction("C")
+            + 144.249 * (fraction("H") - fraction("O") / 8.)
+            + 9.418 * fraction("S")
+    }
+
+    /// mol of atoms of element per kilogram of fuel
+    fn moles(&self, symbol: &str) -> f32 {
+        let atomic_weight = PERIODIC_TABLE.lookup(symbol).unwrap().atomic_weight();
+
+        self.elements.get(symbol).copied().unwrap_or(0.0) * 10. / atomic_weight
+    }
+
+    /// mol of air per kilogram of fuel at excess air in percent
+    fn air_moles(&self, excess_air: f32) -> f32 {
+        let oxygen = AIR
+            .parts()
+            .iter()
+            .zip(AIR.mole_fractions())
+            .find(|((compound, _), _)| compound.substance().to_string() == "O2")
+            .map(|(_, fraction)| fraction)
+            .unwrap();
+
+        self.oxygen_moles() * (1. + excess_air / 100.) / oxygen
+    }
+}
+
+impl From<&Compound> for Fuel {
+    fn from(compound: &Compound) -> Self {
+        Self {
+            elements: compound.element_percents(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fuel;
+    use crate::Error;
+
+    #[test]
+    fn stoichiometric_air() {
+        let octane = Fuel::from_formula("C8H18").unwrap();
+
+        assert!((octane.stoichiometric_oxygen() - 3.502).abs() < 0.002);
+
+        assert!((octane.air_fuel_ratio(0.) - 15.13).abs() < 0.02);
+
+        let methanol = Fuel::from_formula("CH3OH").unwrap();
+
+        assert!((methanol.air_fuel_ratio(0.) - 6.47).abs() < 0.02);
+
+        assert!((methanol.air_fuel_ratio(20.) - 7.76).abs() < 0.02);
+    }
+
+    #[test]
+    fn methane_flue_gas() {
+        let methane = Fuel::from_formula("CH4").unwrap();
+
+        let flue_gas = methane.flue_gas(0.);
+
+        assert!((flue_gas.fraction("CO2") - 0.0949).abs() < 0.001);
+
+        assert!((flue_gas.dry_fraction("CO2") - 0.117).abs() < 0.001);
+
+        assert_eq!(flue_gas.fraction("O2"), 0.);
+
+        assert!(methane.flue_gas(10.).fraction("O2") > 0.);
+    }
+
+    #[test]
+    fn coal_heating_value() {
+        let coal =
+            Fuel::from_composition(&[("C", 75.), ("H", 5.), ("N", 1.5), ("O", 8.), ("S", 1.5)])
+                .unwrap();
+
+        // 25.37 + 5.77 + 0.14 MJ/kg
+        assert!((coal.heating_value() - 31.28).abs() < 0.01);
+
+        assert!(matches!(
+            Fuel::from_composition(&[("Xx", 1.)]),
+            Err(Error::UnknownSymbol(symbol)) if symbol == "Xx"
+        ));
+    }
+}
@@ -0,0 +1,5 @@
+mod flue_gas;
+mod fuel;
+
+pub use flue_gas::FlueGas;
+pub use fuel::Fuel;
@@ -16,7 +16,7 @@ impl Component {
     pub(crate) fn from(element: Element) -> Self {
         Self {
             chemical_element: element.chemical_element(),
-            atoms_count: element.subscript() as usize,
+            atoms_count: element.subscript(),
             mass_percent: 0.0,
         }
     }
@@ -1,37 +1,386 @@
-use crate::tokens::{Element, Substance};
-use crate::Component;
+use crate::chemistry::ChemicalElement;
+use crate::tokens::{self, Element, Substance};
+use crate::{Component, Dissociation, Error, GroupComponent, NitrogenForm, OxidationStates};
 use std::collections::HashMap;
 
 /// A compound parsed from formula
 ///
-/// Contains info about composition and molar mass of compound, defined by formula
+/// Contains info about composition and molar mass of compound, defined by formula,
+/// and keeps parsed tree of formula with groups and hydrate. Compound is a reagent
+/// of given purity: molar mass is mass of reagent per mole of compound and mass
+/// percents are percents of reagent mass. Impurities add to element contents
 #[derive(Clone, Debug, PartialEq)]
 pub struct Compound {
     components: HashMap<&'static str, Component>,
     molar_mass: f32,
+    substance: Substance,
+    purity: f32,
+    impurities: Vec<(Compound, f32)>,
 }
 
 impl Compound {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(substance: Substance) -> Self {
         Self {
             components: HashMap::new(),
             molar_mass: 0.0,
+            substance,
+            purity: 100.,
+            impurities: vec![],
         }
     }
 
-    /// list components
+    /// set purity of reagent in percent of its mass, 100 by default
+    pub fn with_purity(mut self, purity: f32) -> Result<Self, Error> {
+        if purity <= 0.0 || purity > 100.0 || purity + self.impurity_percent() > 100.0 {
+            return Err(Error::OutOfRange("purity".to_string(), purity));
+        }
+
+        self.molar_mass *= self.purity / purity;
+        self.purity = purity;
+
+        self.calculate_mass_percentage();
+
+        Ok(self)
+    }
+
+    /// add impurity defined by formula with percent of reagent mass
+    pub fn with_impurity(mut self, formula: &str, percent: f32) -> Result<Self, Error> {
+        if percent <= 0.0 || self.purity + self.impurity_percent() + percent > 100.0 {
+            return Err(Error::OutOfRange("impurity".to_string(), percent));
+        }
+
+        self.impurities.push((crate::parse(formula)?, percent));
+
+        Ok(self)
+    }
+
+    fn impurity_percent(&self) -> f32 {
+        self.impurities.iter().map(|(_, percent)| percent).sum()
+    }
+
+    /// get purity of reagent in percent
+    pub fn purity(&self) -> f32 {
+        self.purity
+    }
+
+    /// list impurities with percents of reagent mass
+    pub fn impurities(&self) -> &[(Compound, f32)] {
+        &self.impurities
+    }
+
+    /// get mass of pure compound in mass of reagent
+    pub fn pure_mass(&self, mass: f32) -> f32 {
+        mass * self.purity / 100.
+    }
+
+    /// list percents of element mass to reagent mass, of compound and impurities
+    pub fn element_percents(&self) -> HashMap<&'static str, f32> {
+        let mut percents = HashMap::new();
+
+        self.parts().into_iter().for_each(|(compound, percent)| {
+            compound.components.iter().for_each(|(symbol, component)| {
+                *percents.entry(*symbol).or_insert(0.0) +=
+                    component.mass_percent() * percent / 100.;
+            });
+        });
+
+        percents
+    }
+
+    /// list compound and its impurities with percents of reagent mass their
+    /// own mass percents apply to, compound percents are of reagent already
+    pub(crate) fn parts(&self) -> Vec<(&Compound, f32)> {
+        std::iter::once((self, 100.))
+            .chain(
+                self.impurities
+                    .iter()
+                    .map(|(impurity, percent)| (impurity, *percent)),
+            )
+            .collect()
+    }
+
+    /// list components, mass percents are of reagent mass
     pub fn components(&self) -> &HashMap<&'static str, Component> {
         &self.components
     }
 
-    /// get molar mass of compound
+    /// get molar mass of compound, grams of reagent per mole of compound
+    /// when it isn't pure
     pub fn molar_mass(&self) -> f32 {
         self.molar_mass
     }
 
+    /// get ionic charge of compound
+    pub fn charge(&self) -> i32 {
+        self.substance.charge()
+    }
+
+    /// get parsed tree of formula
+    pub fn substance(&self) -> &Substance {
+        &self.substance
+    }
+
+    /// list top-level groups of formula, same groups are merged
+    pub fn groups(&self) -> Vec<GroupComponent> {
+        let mut groups: Vec<GroupComponent> = vec![];
+
+        self.substance.composition().iter().for_each(|component| {
+            if let tokens::Component::Group(group) = component {
+                let count = group.subscript() * self.substance.coefficient();
+
+                match groups
+                    .iter_mut()
+                    .find(|added| added.formula() == GroupComponent::content(group))
+                {
+                    Some(added) => added.add_count(count),
+                    None => groups.push(GroupComponent::from(group, count)),
+                }
+            }
+        });
+
+        groups
+            .iter_mut()
+            .for_each(|group| group.calculate_mass_percent(self.molar_mass));
+
+        groups
+    }
+
+    /// get percent of mass of all groups with given content, like "NO3"
+    pub fn group_percent(&self, formula: &str) -> Option<f32> {
+        self.groups()
+            .iter()
+            .find(|group| group.formula() == formula)
+            .map(|group| group.mass_percent())
+    }
+
+    /// get percent of water of crystallisation mass to reagent mass
+    pub fn hydrate_percent(&self) -> Option<f32> {
+        self.substance.hydrate().map(|hydrate| {
+            let mass: f32 = hydrate
+                .elements()
+                .iter()
+                .map(|element| {
+                    element.chemical_element().atomic_weight() * element.subscript() as f32
+                })
+                .sum();
+
+            mass * self.substance.coefficient() as f32 / self.molar_mass * 100.
+        })
+    }
+
+    /// get percent of reagent mass represented by fragment, like "SO4" or "P2O5",
+    /// carving as many whole fragments as element counts allow
+    pub fn fragment_percent(&self, fragment: &str) -> Result<f32, Error> {
+        let fragment_compound = crate::parse(fragment)?;
+
+        let units = fragment_compound
+            .components()
+            .iter()
+            .map(|(symbol, component)| match self.components.get(symbol) {
+                Some(own) => own.atoms_count() / component.atoms_count(),
+                None => 0,
+            })
+            .min()
+            .unwrap_or(0);
+
+        if units == 0 {
+            return Err(Error::FragmentNotContained(
+                fragment.to_string(),
+                self.substance.to_string(),
+            ));
+        }
+
+        Ok(fragment_compound.molar_mass() * units as f32 / self.molar_mass * 100.)
+    }
+
+    /// get percent of element mass, of compound and impurities, expressed
+    /// as another species, like phosphorus as "P2O5"
+    pub fn element_percent_as(&self, symbol: &str, species: &str) -> Result<f32, Error> {
+        let species_compound = crate::parse(species)?;
+
+        let factor = crate::parse(symbol)?
+            .element_factor(&species_compound, symbol)
+            .ok_or(Error::MissingElement(
+                symbol.to_string(),
+                species.to_string(),
+            ))?;
+
+        let element_percent = self.element_percents().get(symbol).copied().unwrap_or(0.0);
+
+        Ok(element_percent * factor)
+    }
+
+    /// get mass of another compound carrying the same amount of their
+    /// common element as unit mass of this one, like 0.2259 for "NO3" as "N",
+    /// both taken as reagents of their purities.
+    /// oxygen and hydrogen are common elements only of formulas of nothing else
+    pub fn expressed_as(&self, other: &Compound) -> Result<f32, Error> {
+        let carrier = |symbol: &&str| *symbol != "O" && *symbol != "H";
+
+        let mut common: Vec<&'static str> = self
+            .components
+            .keys()
+            .filter(|symbol| other.components.contains_key(*symbol))
+            .copied()
+            .collect();
+
+        if self.components.keys().any(carrier) || other.components.keys().any(carrier) {
+            common.retain(carrier);
+        }
+
+        match common.as_slice() {
+            [symbol] => Ok(self.element_factor(other, symbol).unwrap()),
+            _ => Err(Error::NoCommonElement(
+                self.substance.to_string(),
+                other.substance.to_string(),
+            )),
+        }
+    }
+
+    /// get count of equivalents per formula with its coefficient: charge of ion,
+    /// or total charge of cations of dissociated compound, 1 otherwise. Acids
+    /// count every proton, also those left in anion, like 3 for H3PO4
+    pub fn equivalents(&self) -> usize {
+        if self.charge() != 0 {
+            return self.charge().unsigned_abs() as usize;
+        }
+
+        match self.dissociate() {
+            Ok(dissociation) => {
+                let ions = dissociation.ions();
+
+                let charge: usize = ions
+                    .iter()
+                    .filter(|ion| ion.charge() > 0)
+                    .map(|ion| ion.charge() as usize * ion.count())
+                    .sum();
+
+                let acid = ions
+                    .iter()
+                    .all(|ion| ion.charge() < 0 || ion.formula() == "H+");
+
+                let bound: usize = ions
+                    .iter()
+                    .filter(|ion| acid && ion.charge() < 0)
+                    .map(|ion| match ion.compound().substance().composition() {
+                        [tokens::Component::Element(element), ..]
+                            if element.chemical_element() == ChemicalElement::Hydrogen =>
+                        {
+                            element.subscript() * ion.count()
+                        }
+                        _ => 0,
+                    })
+                    .sum();
+
+                (charge + bound).max(1)
+            }
+            Err(_) => 1,
+        }
+    }
+
+    /// get molar mass per equivalent, grams of reagent when it isn't pure
+    pub fn equivalent_weight(&self) -> f32 {
+        self.molar_mass / self.equivalents() as f32
+    }
+
+    /// get count of water molecules of crystallisation
+    pub fn water(&self) -> usize {
+        self.substance
+            .hydrate()
+            .map(|hydrate| hydrate.coefficient())
+            .unwrap_or(0)
+    }
+
+    /// get single formula unit of pure compound without water of crystallisation
+    pub fn anhydrous(&self) -> Compound {
+        let mut substance = self.substance.with_water(0);
+
+        substance.add_coefficient(1);
+
+        Compound::from(substance)
+    }
+
+    /// get pure hydrate form of compound with count of water molecules
+    pub fn with_water(&self, water: usize) -> Compound {
+        Compound::from(self.substance.with_water(water))
+    }
+
+    /// get mass of another hydrate form of the same substance, like
+    /// grams of "MgSO4" equivalent to given grams of "MgSO4*7H2O",
+    /// purities of both reagents are taken into account by molar masses
+    pub fn equivalent_mass(&self, mass: f32, other: &Compound) -> Result<f32, Error> {
+        match self.is_form_of(other) {
+            true => Ok(mass * other.unit_mass() / self.unit_mass()),
+            false => Err(Error::NotHydrateForm(
+                other.substance.to_string(),
+                self.substance.to_string(),
+            )),
+        }
+    }
+
+    /// molar mass of single formula unit, without coefficient
+    fn unit_mass(&self) -> f32 {
+        self.molar_mass / self.substance.coefficient() as f32
+    }
+
+    /// check if compounds are forms of the same anhydrous substance
+    pub(crate) fn is_form_of(&self, other: &Compound) -> bool {
+        let counts = |compound: &Compound| -> HashMap<&'static str, usize> {
+            compound
+                .anhydrous()
+                .components
+                .iter()
+                .map(|(symbol, component)| (*symbol, component.atoms_count()))
+                .collect()
+        };
+
+        counts(self) == counts(other)
+    }
+
+    /// mass of other compound per mass of this one for the same amount of element
+    pub(crate) fn element_factor(&self, other: &Compound, symbol: &str) -> Option<f32> {
+        let own = self.components.get(symbol)?;
+
+        let others = other.components.get(symbol)?;
+
+        Some(
+            other.molar_mass / others.atoms_count() as f32 * own.atoms_count() as f32
+                / self.molar_mass,
+        )
+    }
+
+    /// get percent of nitrogen mass to reagent mass by form of nitrogen,
+    /// of compound and impurities
+    pub fn nitrogen_forms(&self) -> HashMap<NitrogenForm, f32> {
+        let atomic_weight = crate::ChemicalElement::Nitrogen.atomic_weight();
+
+        let mut forms = HashMap::new();
+
+        self.parts().into_iter().for_each(|(compound, percent)| {
+            NitrogenForm::atoms(compound)
+                .into_iter()
+                .for_each(|(form, atoms)| {
+                    *forms.entry(form).or_insert(0.0) +=
+                        atomic_weight * atoms as f32 / compound.molar_mass * percent;
+                });
+        });
+
+        forms
+    }
+
+    /// split compound into ions
+    pub fn dissociate(&self) -> Result<Dissociation, Error> {
+        Dissociation::try_from(&self.substance)
+    }
+
+    /// assign oxidation states of elements in compound
+    pub fn oxidation_states(&self) -> OxidationStates {
+        OxidationStates::from(self)
+    }
+
     fn add_element(&mut self, element: Element) {
         self.components
-            .entry(&element.chemical_element().symbol())
+            .entry(element.chemical_element().symbol())
             .and_modify(|component| component.add_atoms(element.subscript()))
             .or_insert(Component::from(element));
 
@@ -47,9 +396,11 @@ impl Compound {
 
 impl From<Substance> for Compound {
     fn from(substance: Substance) -> Self {
-        let mut compound = Self::new();
+        let elements = substance.elements();
 
-        substance.elements().iter().for_each(|element| {
+        let mut compound = Self::new(substance);
+
+        elements.iter().for_each(|element| {
             compound.add_element(*element);
         });
 
@@ -63,6 +414,7 @@ impl From<Substance> for Compound {
 mod tests {
     use super::Compound;
     use crate::tokens::{Component, Element, Hydrate, Substance};
+    use crate::{Error, NitrogenForm};
 
     #[test]
     fn molar_mass_calculation() {
@@ -80,4 +432,217 @@ mod tests {
 
         assert_eq!(compound.molar_mass(), MAGNESIUM_SULFATE_MOLAR_MASS);
     }
+
+    #[test]
+    fn group_composition() {
+        let compound = crate::parse("Ca(NO3)2").unwrap();
+
+        let groups = compound.groups();
+
+        assert_eq!(groups.len(), 1);
+
+        assert_eq!(groups[0].formula(), "NO3");
+
+        assert_eq!(groups[0].count(), 2);
+
+        assert!((compound.group_percent("NO3").unwrap() - 75.57).abs() < 0.01);
+
+        assert_eq!(compound.group_percent("SO4"), None);
+    }
+
+    #[test]
+    fn water_of_crystallisation() {
+        let compound = crate::parse("MgSO4*7H2O").unwrap();
+
+        assert!((compound.hydrate_percent().unwrap() - 51.17).abs() < 0.01);
+
+        assert_eq!(crate::parse("MgSO4").unwrap().hydrate_percent(), None);
+    }
+
+    #[test]
+    fn fragments() {
+        let compound = crate::parse("MgSO4*7H2O").unwrap();
+
+        assert!((compound.fragment_percent("SO4").unwrap() - 38.97).abs() < 0.01);
+
+        let compound = crate::parse("Ca(H2PO4)2").unwrap();
+
+        assert!((compound.fragment_percent("P2O5").unwrap() - 60.65).abs() < 0.01);
+
+        assert!(crate::parse("KH2PO4")
+            .unwrap()
+            .fragment_percent("P2O5")
+            .is_err());
+
+        assert!(crate::parse("KNO3")
+            .unwrap()
+            .fragment_percent("NH4")
+            .is_err());
+    }
+
+    #[test]
+    fn element_as_species() {
+        let compound = crate::parse("KH2PO4").unwrap();
+
+        assert!((compound.element_percent_as("P", "P2O5").unwrap() - 52.15).abs() < 0.01);
+
+        assert!((compound.element_percent_as("K", "K2O").unwrap() - 34.61).abs() < 0.01);
+
+        assert_eq!(compound.element_percent_as("N", "NO3").unwrap(), 0.);
+
+        assert!(compound.element_percent_as("P", "K2O").is_err());
+    }
+
+    #[test]
+    fn expressed_as_common_element() {
+        let factor = |from, to| {
+            crate::parse(from)
+                .unwrap()
+                .expressed_as(&crate::parse(to).unwrap())
+        };
+
+        assert!((factor("NO3-", "N").unwrap() - 0.2259).abs() < 0.0001);
+
+        assert!((factor("NH4+", "N").unwrap() - 0.7765).abs() < 0.0001);
+
+        assert!((factor("PO4 3-", "P2O5").unwrap() - 0.7473).abs() < 0.0001);
+
+        assert!((factor("Ca", "CaCO3").unwrap() - 2.4973).abs() < 0.0001);
+
+        assert!(factor("NO3", "PO4").is_err());
+
+        assert!((factor("H2O2", "O2").unwrap() - 0.9407).abs() < 0.0001);
+
+        assert!((crate::express_as(50., "NO3", "N").unwrap() - 11.29).abs() < 0.01);
+    }
+
+    #[test]
+    fn equivalents() {
+        let nitrate = crate::parse("KNO3").unwrap();
+
+        let doubled = crate::parse("2KNO3").unwrap();
+
+        assert_eq!(doubled.equivalents(), 2);
+
+        assert!((doubled.equivalent_weight() - nitrate.equivalent_weight()).abs() < 0.001);
+
+        assert!((crate::parse("2Ca2+").unwrap().equivalent_weight() - 20.04).abs() < 0.01);
+    }
+
+    #[test]
+    fn hydrate_forms() {
+        let epsom = crate::parse("MgSO4*7H2O").unwrap();
+
+        assert_eq!(epsom.water(), 7);
+
+        assert_eq!(epsom.anhydrous(), crate::parse("MgSO4").unwrap());
+
+        let dihydrate = crate::parse("CaCl2*2H2O").unwrap();
+
+        assert_eq!(dihydrate.with_water(6), crate::parse("CaCl2*6H2O").unwrap());
+
+        let mass = dihydrate
+            .equivalent_mass(100., &dihydrate.with_water(6))
+            .unwrap();
+
+        assert!((mass - 149.0).abs() < 0.1);
+
+        assert!(epsom.equivalent_mass(1., &dihydrate).is_err());
+
+        let doubled = crate::parse("2MgSO4*7H2O").unwrap();
+
+        assert_eq!(doubled.anhydrous(), crate::parse("MgSO4").unwrap());
+
+        let mass = doubled
+            .equivalent_mass(246.47, &crate::parse("MgSO4").unwrap())
+            .unwrap();
+
+        assert!((mass - 120.37).abs() < 0.01);
+    }
+
+    #[test]
+    fn purity_and_impurities() {
+        let reagent = crate::parse("KNO3")
+            .unwrap()
+            .with_purity(98.)
+            .unwrap()
+            .with_impurity("NaCl", 1.5)
+            .unwrap();
+
+        let percents = reagent.element_percents();
+
+        assert!((percents["K"] - 37.90).abs() < 0.01);
+
+        assert!((percents["Na"] - 0.59).abs() < 0.01);
+
+        assert!((reagent.pure_mass(50.) - 49.).abs() < 0.001);
+
+        // grams of reagent per mole of KNO3
+        assert!((reagent.molar_mass() - 103.17).abs() < 0.01);
+
+        assert!((reagent.components()["K"].mass_percent() - 37.90).abs() < 0.01);
+
+        let nitrate = crate::parse("Ca(NO3)2").unwrap().with_purity(95.).unwrap();
+
+        assert!((nitrate.group_percent("NO3").unwrap() - 71.79).abs() < 0.01);
+
+        assert!((reagent.fragment_percent("NO3").unwrap() - 60.10).abs() < 0.01);
+
+        let fertiliser = crate::parse("KNO3")
+            .unwrap()
+            .with_purity(98.)
+            .unwrap()
+            .with_impurity("NH4NO3", 2.)
+            .unwrap();
+
+        let forms = fertiliser.nitrogen_forms();
+
+        // 13.58 % of KNO3 plus 0.35 % of NH4NO3
+        assert!((forms[&NitrogenForm::Nitrate] - 13.93).abs() < 0.01);
+
+        assert!((forms[&NitrogenForm::Ammonium] - 0.35).abs() < 0.01);
+
+        let anhydrous = crate::parse("MgSO4").unwrap();
+
+        let mass = anhydrous
+            .equivalent_mass(
+                120.37,
+                &crate::parse("MgSO4*7H2O")
+                    .unwrap()
+                    .with_purity(99.5)
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert!((mass - 247.72).abs() < 0.01);
+    }
+
+    #[test]
+    fn purity_out_of_range() {
+        let reagent = crate::parse("KNO3").unwrap();
+
+        assert!(matches!(
+            reagent.clone().with_purity(0.),
+            Err(Error::OutOfRange(name, _)) if name == "purity"
+        ));
+
+        assert!(reagent.clone().with_purity(-5.).is_err());
+
+        assert!(reagent.clone().with_purity(101.).is_err());
+
+        assert!(reagent
+            .clone()
+            .with_purity(98.)
+            .unwrap()
+            .with_impurity("NaCl", 3.)
+            .is_err());
+
+        assert!(reagent
+            .with_purity(90.)
+            .unwrap()
+            .with_impurity("NaCl", 5.)
+            .unwrap()
+            .with_impurity("KCl", 6.)
+            .is_err());
+    }
 }
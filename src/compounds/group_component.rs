@@ -0,0 +1,68 @@
+use crate::tokens::Group;
+
+/// A group component of compound
+///
+/// Contains formula of parenthesised group, count of its units in compound
+/// and calculated percent of molar mass of the compound
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupComponent {
+    formula: String,
+    unit_mass: f32,
+    count: usize,
+    mass_percent: f32,
+}
+
+impl GroupComponent {
+    pub(crate) fn from(group: &Group, count: usize) -> Self {
+        let unit_mass = group
+            .elements()
+            .iter()
+            .map(|element| element.chemical_element().atomic_weight() * element.subscript() as f32)
+            .sum::<f32>()
+            / group.subscript() as f32;
+
+        Self {
+            formula: Self::content(group),
+            unit_mass,
+            count,
+            mass_percent: 0.0,
+        }
+    }
+
+    /// formula of group without parentheses and subscript
+    pub(crate) fn content(group: &Group) -> String {
+        group
+            .composition()
+            .iter()
+            .map(|component| component.to_string())
+            .collect()
+    }
+
+    pub(crate) fn add_count(&mut self, count: usize) {
+        self.count += count;
+    }
+
+    pub(crate) fn calculate_mass_percent(&mut self, compound_mass: f32) {
+        self.mass_percent = self.mass() / compound_mass * 100.;
+    }
+
+    /// get formula of group, like "NO3"
+    pub fn formula(&self) -> &str {
+        &self.formula
+    }
+
+    /// get count of group units in compound
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// get mass of all group units in compound
+    pub fn mass(&self) -> f32 {
+        self.unit_mass * self.count as f32
+    }
+
+    /// get percent of group mass to compound mass
+    pub fn mass_percent(&self) -> f32 {
+        self.mass_percent
+    }
+}
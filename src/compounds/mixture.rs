@@ -0,0 +1,213 @@
+use crate::compounds::MixtureComponent;
+use crate::{Compound, Error};
+use std::collections::HashMap;
+
+/// A basis amounts of mixture are given in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fraction {
+    Mass,
+    Mole,
+    /// equal to mole fractions for ideal gases
+    Volume,
+}
+
+/// A mixture of compounds
+///
+/// Takes amounts of compounds as fractions, percents or masses of given basis,
+/// they are normalised to their sum. Masses are of reagents of their purities.
+/// Amounts are positive, so sum is never zero, and empty mixture has no
+/// fractions, no components and zero molar mass
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mixture {
+    fraction: Fraction,
+    parts: Vec<(Compound, f32)>,
+}
+
+impl Mixture {
+    pub fn new(fraction: Fraction) -> Self {
+        Self {
+            fraction,
+            parts: vec![],
+        }
+    }
+
+    /// add amount of compound defined by formula
+    pub fn add(&mut self, formula: &str, amount: f32) -> Result<(), Error> {
+        self.add_compound(crate::parse(formula)?, amount)
+    }
+
+    /// add positive amount of parsed compound
+    pub fn add_compound(&mut self, compound: Compound, amount: f32) -> Result<(), Error> {
+        if amount <= 0.0 || !amount.is_finite() {
+            return Err(Error::OutOfRange("amount".to_string(), amount));
+        }
+
+        self.parts.push((compound, amount));
+
+        Ok(())
+    }
+
+    /// list compounds with amounts as given
+    pub fn parts(&self) -> &[(Compound, f32)] {
+        &self.parts
+    }
+
+    /// list mole fractions of compounds, in order they were added
+    pub fn mole_fractions(&self) -> Vec<f32> {
+        let moles: Vec<f32> = self
+            .parts
+            .iter()
+            .map(|(compound, amount)| match self.fraction {
+                Fraction::Mass => amount / compound.molar_mass(),
+                Fraction::Mole | Fraction::Volume => *amount,
+            })
+            .collect();
+
+        normalise(moles)
+    }
+
+    /// list mass fractions of compounds, in order they were added
+    pub fn mass_fractions(&self) -> Vec<f32> {
+        let masses: Vec<f32> = self
+            .parts
+            .iter()
+            .zip(self.mole_fractions())
+            .map(|((compound, _), fraction)| fraction * compound.molar_mass())
+            .collect();
+
+        normalise(masses)
+    }
+
+    /// list partial pressures of compounds of gas mixture at total pressure,
+    /// in unit of total pressure
+    pub fn partial_pressures(&self, pressure: f32) -> Vec<f32> {
+        self.mole_fractions()
+            .iter()
+            .map(|fraction| fraction * pressure)
+            .collect()
+    }
+
+    /// get average molar mass of mixture
+    pub fn molar_mass(&self) -> f32 {
+        self.parts
+            .iter()
+            .zip(self.mole_fractions())
+            .map(|((compound, _), fraction)| fraction * compound.molar_mass())
+            .sum()
+    }
+
+    /// list components of elements, of compounds and their impurities
+    pub fn components(&self) -> HashMap<&'static str, MixtureComponent> {
+        let mut components: HashMap<&'static str, MixtureComponent> = HashMap::new();
+
+        self.parts
+            .iter()
+            .zip(self.mole_fractions())
+            .for_each(|((compound, _), fraction)| {
+                // moles of impurities follow their mass per mole of reagent
+                compound.parts().into_iter().for_each(|(part, percent)| {
+                    let moles =
+                        fraction * compound.molar_mass() * percent / 100. / part.molar_mass();
+
+                    part.components().iter().for_each(|(symbol, component)| {
+                        components
+                            .entry(symbol)
+                            .or_insert_with(|| MixtureComponent::new(component.chemical_element()))
+                            .add_atoms(component.atoms_count() as f32 * moles);
+                    });
+                });
+            });
+
+        let molar_mass = self.molar_mass();
+
+        components
+            .values_mut()
+            .for_each(|component| component.calculate_mass_percent(molar_mass));
+
+        components
+    }
+}
+
+fn normalise(amounts: Vec<f32>) -> Vec<f32> {
+    let total: f32 = amounts.iter().sum();
+
+    amounts.iter().map(|amount| amount / total).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fraction, Mixture};
+
+    #[test]
+    fn air_by_volume() {
+        let mut air = Mixture::new(Fraction::Volume);
+
+        air.add("N2", 78.08).unwrap();
+        air.add("O2", 20.95).unwrap();
+        air.add("Ar", 0.93).unwrap();
+
+        assert!((air.molar_mass() - 28.96).abs() < 0.01);
+
+        assert!((air.components()["O"].mass_percent() - 23.15).abs() < 0.02);
+
+        assert!((air.components()["N"].atoms_count() - 1.5622).abs() < 0.001);
+
+        assert!((air.partial_pressures(101.325)[1] - 21.24).abs() < 0.01);
+    }
+
+    #[test]
+    fn wrong_amounts() {
+        let mut mixture = Mixture::new(Fraction::Mole);
+
+        assert!(mixture.mole_fractions().is_empty());
+
+        assert_eq!(mixture.molar_mass(), 0.0);
+
+        assert!(mixture.components().is_empty());
+
+        assert!(mixture.add("N2", 0.).is_err());
+
+        assert!(mixture.add("N2", -1.).is_err());
+
+        assert!(mixture.add("N2", f32::NAN).is_err());
+
+        assert!(mixture.parts().is_empty());
+    }
+
+    #[test]
+    fn salts_by_mass() {
+        let mut mixture = Mixture::new(Fraction::Mass);
+
+        mixture.add("NaCl", 60.).unwrap();
+        mixture.add("KCl", 40.).unwrap();
+
+        assert!((mixture.components()["Cl"].mass_percent() - 55.42).abs() < 0.01);
+
+        assert!((mixture.mass_fractions()[0] - 0.6).abs() < 0.0001);
+
+        assert!((mixture.mole_fractions()[0] - 0.6567).abs() < 0.001);
+    }
+
+    #[test]
+    fn impure_salts_by_mass() {
+        let mut mixture = Mixture::new(Fraction::Mass);
+
+        mixture
+            .add_compound(
+                crate::parse("NaCl")
+                    .unwrap()
+                    .with_purity(90.)
+                    .unwrap()
+                    .with_impurity("KCl", 10.)
+                    .unwrap(),
+                60.,
+            )
+            .unwrap();
+        mixture.add("KCl", 40.).unwrap();
+
+        // 54 g of NaCl and 46 g of KCl
+        assert!((mixture.components()["Na"].mass_percent() - 21.24).abs() < 0.01);
+
+        assert!((mixture.components()["K"].mass_percent() - 24.12).abs() < 0.01);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::chemistry::ChemicalElement;
+
+/// A component of mixture
+///
+/// Contains average atoms count of element per mole of mixture and
+/// percent of average molar mass of the mixture
+#[derive(Clone, Debug, PartialEq)]
+pub struct MixtureComponent {
+    chemical_element: ChemicalElement,
+    atoms_count: f32,
+    mass_percent: f32,
+}
+
+impl MixtureComponent {
+    pub(crate) fn new(chemical_element: ChemicalElement) -> Self {
+        Self {
+            chemical_element,
+            atoms_count: 0.0,
+            mass_percent: 0.0,
+        }
+    }
+
+    pub(crate) fn add_atoms(&mut self, atoms_count: f32) {
+        self.atoms_count += atoms_count;
+    }
+
+    pub(crate) fn calculate_mass_percent(&mut self, molar_mass: f32) {
+        self.mass_percent = self.mass() / molar_mass * 100.;
+    }
+
+    /// get mass of atoms of element per mole of mixture
+    pub fn mass(&self) -> f32 {
+        self.chemical_element.atomic_weight() * self.atoms_count
+    }
+
+    /// get percent of component mass to mixture mass
+    pub fn mass_percent(&self) -> f32 {
+        self.mass_percent
+    }
+
+    /// get chemical element
+    pub fn chemical_element(&self) -> ChemicalElement {
+        self.chemical_element
+    }
+
+    /// get average atoms count of element per mole of mixture
+    pub fn atoms_count(&self) -> f32 {
+        self.atoms_count
+    }
+}
@@ -1,5 +1,20 @@
 mod component;
 mod compound;
+mod group_component;
+mod mixture;
+mod mixture_component;
+mod nitrogen_form;
+mod oxidation_states;
+mod salt;
+mod thermogravimetry;
 
 pub use component::Component;
 pub use compound::Compound;
+pub use group_component::GroupComponent;
+pub use mixture::{Fraction, Mixture};
+pub use mixture_component::MixtureComponent;
+pub use nitrogen_form::NitrogenForm;
+pub(crate) use oxidation_states::solve;
+pub use oxidation_states::{OxidationState, OxidationStates};
+pub use salt::{Salt, SaltBuilder};
+pub use thermogravimetry::{decomposition, volatile_count, DecompositionStep};
@@ -0,0 +1,136 @@
+use crate::tokens::{Component, Group};
+use crate::Compound;
+use std::collections::HashMap;
+
+/// A chemical form nitrogen is carried in
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NitrogenForm {
+    Nitrate,
+    Ammonium,
+    Amide,
+    Chelate,
+    Other,
+}
+
+impl NitrogenForm {
+    /// counts nitrogen atoms per form in formula unit of compound
+    pub(crate) fn atoms(compound: &Compound) -> HashMap<NitrogenForm, usize> {
+        let mut atoms = HashMap::new();
+
+        if !compound.components().contains_key("N") {
+            return atoms;
+        }
+
+        if let Ok(dissociation) = compound.dissociate() {
+            dissociation.ions().iter().for_each(|ion| {
+                if let Some(nitrogen) = ion.compound().components().get("N") {
+                    let form = match ion.name() {
+                        Some("nitrate") => Self::Nitrate,
+                        Some("ammonium") => Self::Ammonium,
+                        Some(name) if name.ends_with("chelate") => Self::Chelate,
+                        _ => Self::Other,
+                    };
+
+                    *atoms.entry(form).or_insert(0) += nitrogen.atoms_count() * ion.count();
+                }
+            });
+
+            return atoms;
+        }
+
+        if is_urea(compound) {
+            atoms.insert(Self::Amide, compound.components()["N"].atoms_count());
+
+            return atoms;
+        }
+
+        let coefficient = compound.substance().coefficient();
+
+        compound
+            .substance()
+            .composition()
+            .iter()
+            .for_each(|component| match component {
+                Component::Group(group) => {
+                    let content = Group::from(group.composition().to_vec(), 1).elements();
+
+                    let form = match crate::ions::lookup(&content).and_then(|ion| ion.name()) {
+                        Some("nitrate") => Self::Nitrate,
+                        Some("ammonium") => Self::Ammonium,
+                        _ if content == crate::parse_substance("NH2").unwrap().elements() => {
+                            Self::Amide
+                        }
+                        _ => Self::Other,
+                    };
+
+                    group
+                        .elements()
+                        .iter()
+                        .filter(|element| element.chemical_element().symbol() == "N")
+                        .for_each(|element| {
+                            *atoms.entry(form).or_insert(0) += element.subscript() * coefficient;
+                        });
+                }
+
+                Component::Element(element) => {
+                    if element.chemical_element().symbol() == "N" {
+                        *atoms.entry(Self::Other).or_insert(0) += element.subscript() * coefficient;
+                    }
+                }
+            });
+
+        atoms
+    }
+}
+
+fn is_urea(compound: &Compound) -> bool {
+    let urea = crate::parse("CO(NH2)2").unwrap();
+
+    compound.components().len() == urea.components().len()
+        && urea.components().iter().all(|(symbol, component)| {
+            compound
+                .components()
+                .get(symbol)
+                .map(|own| own.atoms_count() == component.atoms_count())
+                .unwrap_or(false)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NitrogenForm;
+
+    #[test]
+    fn forms_of_nitrogen() {
+        let forms = crate::parse("NH4NO3").unwrap().nitrogen_forms();
+
+        assert!((forms[&NitrogenForm::Nitrate] - 17.5).abs() < 0.01);
+
+        assert!((forms[&NitrogenForm::Ammonium] - 17.5).abs() < 0.01);
+
+        let forms = crate::parse("Ca(NO3)2").unwrap().nitrogen_forms();
+
+        assert_eq!(forms.len(), 1);
+
+        assert!(forms.contains_key(&NitrogenForm::Nitrate));
+
+        let forms = crate::parse("C14H18N3O10Fe(NH4)2")
+            .unwrap()
+            .nitrogen_forms();
+
+        assert!(forms.contains_key(&NitrogenForm::Chelate));
+
+        assert!(forms.contains_key(&NitrogenForm::Ammonium));
+    }
+
+    #[test]
+    fn amide_nitrogen() {
+        let forms = crate::parse("CO(NH2)2").unwrap().nitrogen_forms();
+
+        assert!((forms[&NitrogenForm::Amide] - 46.65).abs() < 0.01);
+
+        let forms = crate::parse("CH4N2O").unwrap().nitrogen_forms();
+
+        assert!(forms.contains_key(&NitrogenForm::Amide));
+    }
+}
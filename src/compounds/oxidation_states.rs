@@ -0,0 +1,391 @@
+use crate::chemistry::ChemicalElement;
+use crate::Compound;
+
+/// An oxidation state of chemical element in compound
+///
+/// Keeps total charge of all atoms of element, so average fractional
+/// states (like iron in Fe3O4) are represented exactly
+#[derive(Clone, Debug, PartialEq)]
+pub struct OxidationState {
+    chemical_element: ChemicalElement,
+    atoms_count: usize,
+    total: i32,
+}
+
+impl OxidationState {
+    pub(crate) fn new(chemical_element: ChemicalElement, atoms_count: usize, total: i32) -> Self {
+        Self {
+            chemical_element,
+            atoms_count,
+            total,
+        }
+    }
+
+    /// get chemical element
+    pub fn chemical_element(&self) -> ChemicalElement {
+        self.chemical_element
+    }
+
+    /// get atoms count of element in compound
+    pub fn atoms_count(&self) -> usize {
+        self.atoms_count
+    }
+
+    /// get sum of oxidation states of all atoms of element
+    pub fn total(&self) -> i32 {
+        self.total
+    }
+
+    /// get oxidation state of single atom, averaged if fractional
+    pub fn value(&self) -> f32 {
+        self.total as f32 / self.atoms_count as f32
+    }
+
+    /// check if atoms of element have average fractional state
+    pub fn is_fractional(&self) -> bool {
+        self.total % self.atoms_count as i32 != 0
+    }
+}
+
+/// A result of oxidation states assignment
+#[derive(Clone, Debug, PartialEq)]
+pub enum OxidationStates {
+    /// single charge-neutral assignment was found
+    Determined(Vec<OxidationState>),
+
+    /// several assignments are equally plausible
+    Ambiguous(Vec<Vec<OxidationState>>),
+
+    /// no assignment within common oxidation states balances the charge
+    Impossible,
+}
+
+impl From<&Compound> for OxidationStates {
+    fn from(compound: &Compound) -> Self {
+        let mut elements: Vec<(ChemicalElement, usize)> = compound
+            .components()
+            .values()
+            .map(|component| (component.chemical_element(), component.atoms_count()))
+            .collect();
+
+        elements.sort_by(|a, b| {
+            let a_electronegativity = a.0.electronegativity().unwrap_or(0.);
+            let b_electronegativity = b.0.electronegativity().unwrap_or(0.);
+
+            a_electronegativity
+                .total_cmp(&b_electronegativity)
+                .then(a.0.symbol().cmp(b.0.symbol()))
+        });
+
+        let charge = compound.charge();
+
+        if elements.len() == 1 {
+            let (chemical_element, atoms_count) = elements[0];
+
+            return Self::Determined(vec![OxidationState::new(
+                chemical_element,
+                atoms_count,
+                charge,
+            )]);
+        }
+
+        let counts: Vec<usize> = elements.iter().map(|(_, count)| *count).collect();
+
+        let anion = elements
+            .iter()
+            .rposition(|(element, _)| element.electronegativity().is_some());
+
+        let candidates: Vec<Vec<i32>> = elements
+            .iter()
+            .enumerate()
+            .map(|(index, (element, _))| preferred_states(*element, Some(index) == anion))
+            .collect();
+
+        let solutions: Vec<(Vec<i32>, usize)> = solve(&counts, &candidates, charge)
+            .into_iter()
+            .map(|(states, rank)| (totals(&counts, &states), rank))
+            .collect();
+
+        if !solutions.is_empty() {
+            return Self::select(&elements, solutions);
+        }
+
+        // no integral assignment, so let one element take an average state:
+        // first try elements with variable states, then anion and hydrogen
+        let relaxations = [false, true];
+
+        for relax_fixed in relaxations {
+            let mut solutions = vec![];
+
+            for (index, (element, count)) in elements.iter().enumerate() {
+                let fixed = Some(index) == anion || *element == ChemicalElement::Hydrogen;
+
+                if fixed != relax_fixed {
+                    continue;
+                }
+
+                let (min, max) = match range(*element) {
+                    Some(range) => range,
+                    None => continue,
+                };
+
+                let lowest = min * *count as i32;
+
+                let mut relaxed_counts = counts.clone();
+                let mut relaxed_candidates = candidates.clone();
+
+                relaxed_counts[index] = 1;
+                relaxed_candidates[index] = (lowest..=max * *count as i32).collect();
+
+                for (states, rank) in solve(&relaxed_counts, &relaxed_candidates, charge) {
+                    let position = (states[index] - lowest) as usize;
+
+                    solutions.push((totals(&relaxed_counts, &states), rank - position));
+                }
+            }
+
+            if !solutions.is_empty() {
+                return Self::select(&elements, solutions);
+            }
+        }
+
+        Self::Impossible
+    }
+}
+
+impl OxidationStates {
+    fn select(elements: &[(ChemicalElement, usize)], solutions: Vec<(Vec<i32>, usize)>) -> Self {
+        // solutions hold total states of all atoms of each element,
+        // integral ones are preferred over averages
+        let integral = |totals: &Vec<i32>| {
+            elements
+                .iter()
+                .zip(totals)
+                .all(|((_, count), total)| total % *count as i32 == 0)
+        };
+
+        let solutions: Vec<(Vec<i32>, usize)> =
+            match solutions.iter().any(|(totals, _)| integral(totals)) {
+                true => solutions
+                    .into_iter()
+                    .filter(|(totals, _)| integral(totals))
+                    .collect(),
+                false => solutions,
+            };
+
+        let best_rank = solutions.iter().map(|(_, rank)| *rank).min().unwrap();
+
+        let mut totals: Vec<Vec<i32>> = solutions
+            .into_iter()
+            .filter(|(_, rank)| *rank == best_rank)
+            .map(|(totals, _)| totals)
+            .collect();
+
+        // same assignment may be reached by several search paths
+        totals.sort();
+        totals.dedup();
+
+        let mut best: Vec<Vec<OxidationState>> = totals
+            .into_iter()
+            .map(|totals| {
+                elements
+                    .iter()
+                    .zip(totals)
+                    .map(|((element, count), total)| OxidationState::new(*element, *count, total))
+                    .collect()
+            })
+            .collect();
+
+        match best.len() {
+            1 => Self::Determined(best.remove(0)),
+            _ => Self::Ambiguous(best),
+        }
+    }
+}
+
+/// narrows common states: the most electronegative element takes its
+/// primary negative state and hydrogen bonded to it is +1
+fn preferred_states(element: ChemicalElement, is_anion: bool) -> Vec<i32> {
+    let states = element.oxidation_states();
+
+    if is_anion {
+        if let Some(state) = states.iter().find(|state| **state < 0) {
+            return vec![*state];
+        }
+    } else if element == ChemicalElement::Hydrogen {
+        return vec![1];
+    }
+
+    states.to_vec()
+}
+
+fn totals(counts: &[usize], states: &[i32]) -> Vec<i32> {
+    counts
+        .iter()
+        .zip(states)
+        .map(|(count, state)| *count as i32 * state)
+        .collect()
+}
+
+fn range(element: ChemicalElement) -> Option<(i32, i32)> {
+    let states = element.oxidation_states();
+
+    Some((*states.iter().min()?, *states.iter().max()?))
+}
+
+/// enumerates per-element states (one state for all atoms of element),
+/// which sum up to target charge, with rank of candidates positions
+pub(crate) fn solve(
+    counts: &[usize],
+    candidates: &[Vec<i32>],
+    target: i32,
+) -> Vec<(Vec<i32>, usize)> {
+    let mut solutions = vec![];
+
+    let mut states = vec![0; counts.len()];
+
+    search(
+        counts,
+        candidates,
+        target,
+        0,
+        0,
+        &mut states,
+        &mut solutions,
+    );
+
+    solutions
+}
+
+fn search(
+    counts: &[usize],
+    candidates: &[Vec<i32>],
+    remaining: i32,
+    rank: usize,
+    index: usize,
+    states: &mut Vec<i32>,
+    solutions: &mut Vec<(Vec<i32>, usize)>,
+) {
+    if index == counts.len() {
+        if remaining == 0 {
+            solutions.push((states.clone(), rank));
+        }
+
+        return;
+    }
+
+    for (position, state) in candidates[index].iter().enumerate() {
+        states[index] = *state;
+
+        search(
+            counts,
+            candidates,
+            remaining - counts[index] as i32 * state,
+            rank + position,
+            index + 1,
+            states,
+            solutions,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OxidationStates;
+    use crate::chemistry::ChemicalElement;
+
+    fn determined(formula: &str) -> Vec<(ChemicalElement, f32)> {
+        match crate::parse(formula).unwrap().oxidation_states() {
+            OxidationStates::Determined(states) => states
+                .iter()
+                .map(|state| (state.chemical_element(), state.value()))
+                .collect(),
+            states => panic!("{} is not determined: {:?}", formula, states),
+        }
+    }
+
+    #[test]
+    fn integral_states() {
+        assert_eq!(
+            determined("K2Cr2O7"),
+            vec![
+                (ChemicalElement::Potassium, 1.),
+                (ChemicalElement::Chromium, 6.),
+                (ChemicalElement::Oxygen, -2.),
+            ]
+        );
+
+        assert_eq!(
+            determined("Na2S2O3"),
+            vec![
+                (ChemicalElement::Sodium, 1.),
+                (ChemicalElement::Sulfur, 2.),
+                (ChemicalElement::Oxygen, -2.),
+            ]
+        );
+
+        assert_eq!(
+            determined("H2O2"),
+            vec![
+                (ChemicalElement::Hydrogen, 1.),
+                (ChemicalElement::Oxygen, -1.),
+            ]
+        );
+    }
+
+    #[test]
+    fn fractional_states() {
+        assert_eq!(
+            determined("Fe3O4"),
+            vec![
+                (ChemicalElement::Iron, 8. / 3.),
+                (ChemicalElement::Oxygen, -2.),
+            ]
+        );
+    }
+
+    #[test]
+    fn ion_charge() {
+        assert_eq!(
+            determined("SO4 2-"),
+            vec![
+                (ChemicalElement::Sulfur, 6.),
+                (ChemicalElement::Oxygen, -2.),
+            ]
+        );
+
+        assert_eq!(
+            determined("NH4+"),
+            vec![
+                (ChemicalElement::Hydrogen, 1.),
+                (ChemicalElement::Nitrogen, -3.),
+            ]
+        );
+    }
+
+    #[test]
+    fn ambiguous_and_impossible() {
+        assert!(matches!(
+            crate::parse("CuFeS2").unwrap().oxidation_states(),
+            OxidationStates::Ambiguous(_)
+        ));
+
+        assert_eq!(
+            crate::parse("NaAr").unwrap().oxidation_states(),
+            OxidationStates::Impossible
+        );
+    }
+
+    #[test]
+    fn repeated_solutions() {
+        let elements = [(ChemicalElement::Iron, 1), (ChemicalElement::Sulfur, 1)];
+
+        // duplicates are not adjacent
+        let solutions = vec![(vec![2, -2], 0), (vec![3, -3], 0), (vec![2, -2], 0)];
+
+        match OxidationStates::select(&elements, solutions) {
+            OxidationStates::Ambiguous(states) => assert_eq!(states.len(), 2),
+            states => panic!("{:?} is not ambiguous", states),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::tokens::{Component, Element, Group, Substance};
+use crate::{Compound, Error};
+
+/// A neutral salt built from cation and anion
+#[derive(Clone, Debug, PartialEq)]
+pub struct Salt {
+    formula: String,
+    compound: Compound,
+}
+
+impl Salt {
+    /// create builder of salt
+    pub fn builder<'a>() -> SaltBuilder<'a> {
+        SaltBuilder::new()
+    }
+
+    /// get formatted formula of salt
+    pub fn formula(&self) -> &str {
+        &self.formula
+    }
+
+    /// get compound of salt
+    pub fn compound(&self) -> &Compound {
+        &self.compound
+    }
+}
+
+/// A builder of neutral salt
+///
+/// Takes charged species, like "Ca2+" and "PO4 3-", and combines them
+/// in the lowest charge-neutral ratio
+#[derive(Clone, Debug, Default)]
+pub struct SaltBuilder<'a> {
+    cation: Option<&'a str>,
+    anion: Option<&'a str>,
+}
+
+impl<'a> SaltBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// set formula of positively charged species
+    pub fn cation(mut self, formula: &'a str) -> Self {
+        self.cation = Some(formula);
+        self
+    }
+
+    /// set formula of negatively charged species
+    pub fn anion(mut self, formula: &'a str) -> Self {
+        self.anion = Some(formula);
+        self
+    }
+
+    pub fn build(self) -> Result<Salt, Error> {
+        let cation = Self::ion(self.cation, "cation", 1)?;
+
+        let anion = Self::ion(self.anion, "anion", -1)?;
+
+        let cation_charge = cation.charge().unsigned_abs() as usize;
+
+        let anion_charge = anion.charge().unsigned_abs() as usize;
+
+        let divisor = gcd(cation_charge, anion_charge);
+
+        let mut substance = Substance::new();
+
+        Self::add_ion(&mut substance, &cation, anion_charge / divisor);
+
+        Self::add_ion(&mut substance, &anion, cation_charge / divisor);
+
+        Ok(Salt {
+            formula: substance.to_string(),
+            compound: Compound::from(substance),
+        })
+    }
+
+    fn ion(formula: Option<&str>, name: &str, sign: i32) -> Result<Substance, Error> {
+        let formula = formula.ok_or(Error::MissingIon(name.to_string()))?;
+
+        let substance = crate::parse_substance(formula)?;
+
+        // ions are combined from their composition, so coefficient
+        // and water of crystallisation can't be carried into salt
+        if substance.charge().signum() != sign
+            || substance.coefficient() != 1
+            || substance.hydrate().is_some()
+        {
+            return Err(Error::UnexpectedCharge(
+                formula.to_string(),
+                substance.charge(),
+            ));
+        }
+
+        Ok(substance)
+    }
+
+    /// polyatomic ions are grouped when repeated, single elements get subscript
+    fn add_ion(substance: &mut Substance, ion: &Substance, count: usize) {
+        match ion.composition() {
+            [Component::Element(element)] => {
+                substance.add_component(Component::Element(Element::multiply(element, count)));
+            }
+
+            composition if count > 1 => {
+                substance.add_component(Component::Group(Group::from(composition.to_vec(), count)));
+            }
+
+            composition => composition
+                .iter()
+                .for_each(|component| substance.add_component(component.clone())),
+        }
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Salt;
+
+    #[test]
+    fn charge_neutral_ratio() {
+        let salt = |cation, anion| Salt::builder().cation(cation).anion(anion).build().unwrap();
+
+        assert_eq!(salt("Ca2+", "PO4 3-").formula(), "Ca3(PO4)2");
+
+        assert_eq!(salt("NH4+", "NO3-").formula(), "NH4NO3");
+
+        assert_eq!(salt("Al3+", "SO4 2-").formula(), "Al2(SO4)3");
+
+        assert_eq!(salt("NH4+", "SO4 2-").formula(), "(NH4)2SO4");
+
+        assert_eq!(salt("Mg2+", "SO4 2-").formula(), "MgSO4");
+
+        let compound = salt("Ca2+", "PO4 3-").compound().clone();
+
+        assert_eq!(compound, crate::parse("Ca3(PO4)2").unwrap());
+
+        assert_eq!(compound.charge(), 0);
+    }
+
+    #[test]
+    fn wrong_charges() {
+        assert!(Salt::builder().cation("NO3-").anion("Cl-").build().is_err());
+
+        assert!(Salt::builder().cation("Na+").anion("Cl").build().is_err());
+
+        assert!(Salt::builder().anion("Cl-").build().is_err());
+
+        assert!(Salt::builder()
+            .cation("2Ca2+")
+            .anion("Cl-")
+            .build()
+            .is_err());
+
+        assert!(Salt::builder()
+            .cation("Ca2+")
+            .anion("Cl-*2H2O")
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{Compound, Error};
+
+/// A step of thermal decomposition
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecompositionStep {
+    product: Compound,
+    loss: f32,
+    total_loss: f32,
+}
+
+impl DecompositionStep {
+    /// get product of step
+    pub fn product(&self) -> &Compound {
+        &self.product
+    }
+
+    /// get mass lost in step in percent of initial mass
+    pub fn loss(&self) -> f32 {
+        self.loss
+    }
+
+    /// get mass lost since start in percent of initial mass
+    pub fn total_loss(&self) -> f32 {
+        self.total_loss
+    }
+
+    /// get residual mass in percent of initial mass
+    pub fn residue(&self) -> f32 {
+        100. - self.total_loss
+    }
+}
+
+/// A function infers count of volatile molecules, like water of crystallisation
+/// or CO2, lost by compound of anhydrous formula plus volatile on heating
+pub fn volatile_count<'a>(
+    anhydrous: impl Into<&'a str>,
+    volatile: impl Into<&'a str>,
+    mass_loss: f32,
+) -> Result<f32, Error> {
+    // anhydrous residue is left, so loss stays below 100 %
+    if !(0.0..100.).contains(&mass_loss) {
+        return Err(Error::OutOfRange("mass loss".to_string(), mass_loss));
+    }
+
+    let anhydrous = crate::parse(anhydrous)?;
+
+    let volatile = crate::parse(volatile)?;
+
+    Ok(mass_loss * anhydrous.molar_mass() / (volatile.molar_mass() * (100. - mass_loss)))
+}
+
+/// A function lists expected mass losses of each step of thermal decomposition
+/// from first formula through the next, like "CaC2O4*H2O", "CaC2O4", "CaCO3", "CaO".
+/// amounts of products follow the metal they share, or another single common element
+pub fn decomposition(formulas: &[&str]) -> Result<Vec<DecompositionStep>, Error> {
+    let compounds = formulas
+        .iter()
+        .map(|formula| crate::parse(*formula))
+        .collect::<Result<Vec<Compound>, Error>>()?;
+
+    let mut steps = vec![];
+
+    let mut residue = 100.;
+
+    for pair in compounds.windows(2) {
+        let product = residue * conversion(&pair[0], &pair[1])?;
+
+        let total_loss = 100. - product;
+
+        steps.push(DecompositionStep {
+            product: pair[1].clone(),
+            loss: residue - product,
+            total_loss,
+        });
+
+        residue = product;
+    }
+
+    Ok(steps)
+}
+
+/// grams of product per gram of reactant, conserving common metal
+fn conversion(reactant: &Compound, product: &Compound) -> Result<f32, Error> {
+    let metals: Vec<&str> = reactant
+        .components()
+        .iter()
+        .filter(|(symbol, component)| {
+            component.chemical_element().is_metal() && product.components().contains_key(*symbol)
+        })
+        .map(|(symbol, _)| *symbol)
+        .collect();
+
+    match metals.as_slice() {
+        [symbol] => Ok(reactant.element_factor(product, symbol).unwrap()),
+        _ => reactant.expressed_as(product),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decomposition, volatile_count};
+
+    #[test]
+    fn water_from_mass_loss() {
+        assert!((volatile_count("ZnSO4", "H2O", 43.9).unwrap() - 7.0).abs() < 0.05);
+
+        assert!((volatile_count("CaO", "CO2", 43.97).unwrap() - 1.0).abs() < 0.01);
+
+        assert!(volatile_count("ZnSO4", "H2O", 100.).is_err());
+
+        assert!(volatile_count("ZnSO4", "H2O", -5.).is_err());
+    }
+
+    #[test]
+    fn calcium_oxalate_steps() {
+        let steps = decomposition(&["CaC2O4*H2O", "CaC2O4", "CaCO3", "CaO"]).unwrap();
+
+        let losses: Vec<f32> = steps.iter().map(|step| step.loss()).collect();
+
+        assert!((losses[0] - 12.33).abs() < 0.01);
+
+        assert!((losses[1] - 19.17).abs() < 0.01);
+
+        assert!((losses[2] - 30.12).abs() < 0.01);
+
+        assert!((steps[2].residue() - 38.38).abs() < 0.01);
+    }
+}
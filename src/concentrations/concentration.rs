@@ -0,0 +1,48 @@
+use crate::concentrations::ConcentrationUnit;
+
+/// A concentration of solute in solution, typed by unit
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Concentration {
+    /// mol per litre of solution
+    Molarity(f32),
+    /// mol per kilogram of solvent
+    Molality(f32),
+    /// equivalents per litre of solution
+    Normality(f32),
+    /// mg per kilogram of solution
+    Ppm(f32),
+    /// grams per litre of solution
+    GramsPerLitre(f32),
+    /// mg per litre of solution
+    MgPerLitre(f32),
+    /// meq per litre of solution
+    MeqPerLitre(f32),
+}
+
+impl Concentration {
+    /// get value in unit of concentration
+    pub fn value(&self) -> f32 {
+        match self {
+            Self::Molarity(value)
+            | Self::Molality(value)
+            | Self::Normality(value)
+            | Self::Ppm(value)
+            | Self::GramsPerLitre(value)
+            | Self::MgPerLitre(value)
+            | Self::MeqPerLitre(value) => *value,
+        }
+    }
+
+    /// get unit of concentration
+    pub fn unit(&self) -> ConcentrationUnit {
+        match self {
+            Self::Molarity(_) => ConcentrationUnit::Molarity,
+            Self::Molality(_) => ConcentrationUnit::Molality,
+            Self::Normality(_) => ConcentrationUnit::Normality,
+            Self::Ppm(_) => ConcentrationUnit::Ppm,
+            Self::GramsPerLitre(_) => ConcentrationUnit::GramsPerLitre,
+            Self::MgPerLitre(_) => ConcentrationUnit::MgPerLitre,
+            Self::MeqPerLitre(_) => ConcentrationUnit::MeqPerLitre,
+        }
+    }
+}
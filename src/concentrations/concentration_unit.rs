@@ -0,0 +1,18 @@
+/// A unit of concentration to convert to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConcentrationUnit {
+    /// mol per litre of solution
+    Molarity,
+    /// mol per kilogram of solvent
+    Molality,
+    /// equivalents per litre of solution
+    Normality,
+    /// mg per kilogram of solution
+    Ppm,
+    /// grams per litre of solution
+    GramsPerLitre,
+    /// mg per litre of solution
+    MgPerLitre,
+    /// meq per litre of solution
+    MeqPerLitre,
+}
@@ -0,0 +1,9 @@
+mod concentration;
+mod concentration_unit;
+mod preparation;
+mod solute;
+
+pub use concentration::Concentration;
+pub use concentration_unit::ConcentrationUnit;
+pub use preparation::Preparation;
+pub use solute::Solute;
@@ -0,0 +1,108 @@
+use crate::concentrations::{Concentration, Solute};
+use crate::{Compound, Error};
+
+/// A preparation of solution
+///
+/// Takes solute, its target concentration and volume of solution in litres,
+/// and finds mass of reagent to weigh or volume of stock solution to dilute.
+/// Reagents may be other hydrate forms of solute, or any compound
+/// sharing a single element with it, their purities come with compounds
+#[derive(Clone, Debug, PartialEq)]
+pub struct Preparation {
+    solute: Solute,
+    concentration: Concentration,
+    volume: f32,
+}
+
+impl Preparation {
+    pub fn new(solute: Solute, concentration: Concentration, volume: f32) -> Self {
+        Self {
+            solute,
+            concentration,
+            volume,
+        }
+    }
+
+    /// get grams of solute, or of its reagent when it isn't pure, in solution
+    pub fn solute_mass(&self) -> f32 {
+        self.solute.grams_per_litre(self.concentration) * self.volume
+    }
+
+    /// get grams of reagent defined by formula to weigh
+    pub fn mass(&self, reagent: &str) -> Result<f32, Error> {
+        self.reagent_mass(&crate::parse(reagent)?)
+    }
+
+    /// get grams of reagent compound of its purity to weigh
+    pub fn reagent_mass(&self, reagent: &Compound) -> Result<f32, Error> {
+        Ok(self.solute_mass() * factor(self.solute.compound(), reagent)?)
+    }
+
+    /// get litres of stock solution to dilute to volume of solution
+    pub fn stock_volume(&self, stock: &Solute, concentration: Concentration) -> Result<f32, Error> {
+        let factor = factor(self.solute.compound(), stock.compound())?;
+
+        let volume = self.solute_mass() * factor / stock.grams_per_litre(concentration);
+
+        match volume > self.volume {
+            true => Err(Error::DiluteStock(stock.compound().substance().to_string())),
+            false => Ok(volume),
+        }
+    }
+}
+
+/// grams of reagent per gram of solute, purity of solute cancels out
+/// as both masses follow molar masses
+fn factor(solute: &Compound, reagent: &Compound) -> Result<f32, Error> {
+    solute
+        .equivalent_mass(1., reagent)
+        .or_else(|_| solute.expressed_as(reagent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Preparation;
+    use crate::{Concentration, Solute};
+
+    #[test]
+    fn mass_of_hydrate() {
+        let solute = Solute::from_formula("CuSO4").unwrap();
+
+        let preparation = Preparation::new(solute, Concentration::Molarity(0.2), 0.5);
+
+        assert!((preparation.mass("CuSO4*5H2O").unwrap() - 24.97).abs() < 0.01);
+
+        let reagent = crate::parse("CuSO4*5H2O")
+            .unwrap()
+            .with_purity(98.)
+            .unwrap();
+
+        assert!((preparation.reagent_mass(&reagent).unwrap() - 25.48).abs() < 0.01);
+
+        assert!(preparation.mass("KNO3").is_err());
+
+        // purity of solute is not applied twice
+        let solute = Solute::new(crate::parse("CuSO4").unwrap().with_purity(98.).unwrap());
+
+        let preparation = Preparation::new(solute, Concentration::Molarity(0.2), 0.5);
+
+        assert!((preparation.mass("CuSO4*5H2O").unwrap() - 24.97).abs() < 0.01);
+    }
+
+    #[test]
+    fn dilution_of_stock() {
+        let solute = Solute::from_formula("HCl").unwrap();
+
+        let preparation = Preparation::new(solute.clone(), Concentration::Molarity(0.1), 1.);
+
+        let volume = preparation
+            .stock_volume(&solute, Concentration::Molarity(2.))
+            .unwrap();
+
+        assert!((volume - 0.05).abs() < 0.0001);
+
+        assert!(preparation
+            .stock_volume(&solute, Concentration::Molarity(0.05))
+            .is_err());
+    }
+}
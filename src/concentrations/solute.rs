@@ -0,0 +1,153 @@
+use crate::concentrations::{Concentration, ConcentrationUnit};
+use crate::{Compound, Error};
+
+/// A solute converting concentrations between units
+///
+/// Takes compound, its n-factor for equivalents, by default charge of ion
+/// or of cations, and density of solution in kg/L for mass-based units
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solute {
+    compound: Compound,
+    n_factor: f32,
+    density: f32,
+}
+
+impl Solute {
+    pub fn new(compound: Compound) -> Self {
+        Self {
+            n_factor: compound.equivalents() as f32,
+            compound,
+            density: 1.0,
+        }
+    }
+
+    /// create solute of compound defined by formula
+    pub fn from_formula(formula: &str) -> Result<Self, Error> {
+        Ok(Self::new(crate::parse(formula)?))
+    }
+
+    /// set equivalents per mole, like 2 for H2SO4 or 5 for KMnO4 in redox
+    pub fn n_factor(mut self, n_factor: f32) -> Self {
+        self.n_factor = n_factor;
+        self
+    }
+
+    /// set density of solution in kg/L
+    pub fn density(mut self, density: f32) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// get compound of solute
+    pub fn compound(&self) -> &Compound {
+        &self.compound
+    }
+
+    /// get grams of solute per litre of solution
+    pub fn grams_per_litre(&self, concentration: Concentration) -> f32 {
+        let molar_mass = self.compound.molar_mass();
+
+        match concentration {
+            Concentration::Molarity(value) => value * molar_mass,
+            Concentration::Normality(value) => value / self.n_factor * molar_mass,
+            Concentration::MeqPerLitre(value) => value / 1000. / self.n_factor * molar_mass,
+            Concentration::GramsPerLitre(value) => value,
+            Concentration::MgPerLitre(value) => value / 1000.,
+            Concentration::Ppm(value) => value / 1000. * self.density,
+            // solvent is solution mass less solute mass
+            Concentration::Molality(value) => {
+                1000. * value * self.density * molar_mass / (1000. + value * molar_mass)
+            }
+        }
+    }
+
+    /// get mg of solute per litre of solution
+    pub fn mg_per_litre(&self, concentration: Concentration) -> f32 {
+        self.grams_per_litre(concentration) * 1000.
+    }
+
+    /// get mol of solute per litre of solution
+    pub fn molarity(&self, concentration: Concentration) -> f32 {
+        self.grams_per_litre(concentration) / self.compound.molar_mass()
+    }
+
+    /// get equivalents of solute per litre of solution
+    pub fn normality(&self, concentration: Concentration) -> f32 {
+        self.molarity(concentration) * self.n_factor
+    }
+
+    /// get meq of solute per litre of solution
+    pub fn meq_per_litre(&self, concentration: Concentration) -> f32 {
+        self.normality(concentration) * 1000.
+    }
+
+    /// get mg of solute per kilogram of solution
+    pub fn ppm(&self, concentration: Concentration) -> f32 {
+        self.mg_per_litre(concentration) / self.density
+    }
+
+    /// get mol of solute per kilogram of solvent
+    pub fn molality(&self, concentration: Concentration) -> f32 {
+        let grams = self.grams_per_litre(concentration);
+
+        1000. * grams / (self.compound.molar_mass() * (1000. * self.density - grams))
+    }
+
+    /// convert concentration to another unit
+    pub fn convert(&self, concentration: Concentration, unit: ConcentrationUnit) -> Concentration {
+        match unit {
+            ConcentrationUnit::Molarity => Concentration::Molarity(self.molarity(concentration)),
+            ConcentrationUnit::Molality => Concentration::Molality(self.molality(concentration)),
+            ConcentrationUnit::Normality => Concentration::Normality(self.normality(concentration)),
+            ConcentrationUnit::Ppm => Concentration::Ppm(self.ppm(concentration)),
+            ConcentrationUnit::GramsPerLitre => {
+                Concentration::GramsPerLitre(self.grams_per_litre(concentration))
+            }
+            ConcentrationUnit::MgPerLitre => {
+                Concentration::MgPerLitre(self.mg_per_litre(concentration))
+            }
+            ConcentrationUnit::MeqPerLitre => {
+                Concentration::MeqPerLitre(self.meq_per_litre(concentration))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solute;
+    use crate::{Concentration, ConcentrationUnit};
+
+    #[test]
+    fn sulfuric_acid() {
+        let solute = Solute::from_formula("H2SO4").unwrap();
+
+        let normal = Concentration::Normality(0.1);
+
+        assert!((solute.grams_per_litre(normal) - 4.904).abs() < 0.001);
+
+        assert!((solute.molarity(normal) - 0.05).abs() < 0.0001);
+
+        assert!((solute.meq_per_litre(normal) - 100.).abs() < 0.01);
+
+        let converted = solute.convert(Concentration::Molarity(0.05), ConcentrationUnit::Normality);
+
+        assert_eq!(converted.unit(), ConcentrationUnit::Normality);
+
+        assert!((converted.value() - 0.1).abs() < 0.0001);
+    }
+
+    #[test]
+    fn mass_based_units() {
+        // 1 mol/kg NaCl solution of density 1.0369 kg/L is 1058.44 g in 1.0208 L
+        let solute = Solute::from_formula("NaCl").unwrap().density(1.0369);
+
+        assert!((solute.molarity(Concentration::Molality(1.)) - 0.9797).abs() < 0.001);
+
+        let molality = solute.molality(Concentration::Molarity(0.9797));
+
+        assert!((molality - 1.).abs() < 0.001);
+
+        assert!((solute.ppm(Concentration::MgPerLitre(1036.9)) - 1000.).abs() < 0.01);
+    }
+}
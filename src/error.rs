@@ -1,9 +1,25 @@
-/// An enumeration of parsing errors
+/// An enumeration of parsing and calculation errors
 #[derive(Debug)]
 pub enum Error {
     UnexpectedToken(String, usize),
     UnexpectedEnd(String),
     UnknownElement(String, usize),
+    UnexpectedCharge(String, i32),
+    MissingIon(String),
+    Undissociable(String),
+    FragmentNotContained(String, String),
+    MissingElement(String, String),
+    InvalidLabel(String),
+    Incompatible(String),
+    UnknownSolubility(String),
+    InvalidAnalysis(String),
+    NoCommonElement(String, String),
+    DiluteStock(String),
+    NotHydrateForm(String, String),
+    UnknownGas(String),
+    OutOfRange(String, f32),
+    UnknownSymbol(String),
+    NotConverged(String),
 }
 
 impl std::fmt::Display for Error {
@@ -28,6 +44,90 @@ impl std::fmt::Display for Error {
                     element, position
                 )
             }
+
+            Self::UnexpectedCharge(formula, charge) => {
+                write!(f, "unexpected charge of \"{}\": {}", formula, charge)
+            }
+
+            Self::MissingIon(ion) => {
+                write!(f, "missing ion: {}", ion)
+            }
+
+            Self::Undissociable(formula) => {
+                write!(f, "can't dissociate into ions: \"{}\"", formula)
+            }
+
+            Self::FragmentNotContained(fragment, formula) => {
+                write!(
+                    f,
+                    "fragment \"{}\" is not contained in \"{}\"",
+                    fragment, formula
+                )
+            }
+
+            Self::MissingElement(symbol, formula) => {
+                write!(f, "element \"{}\" is missing in \"{}\"", symbol, formula)
+            }
+
+            Self::InvalidLabel(label) => {
+                write!(f, "invalid fertiliser label: \"{}\"", label)
+            }
+
+            Self::Incompatible(formula) => {
+                write!(
+                    f,
+                    "no tank is free of compounds incompatible with \"{}\"",
+                    formula
+                )
+            }
+
+            Self::UnknownSolubility(formula) => {
+                write!(f, "no solubility data for \"{}\"", formula)
+            }
+
+            Self::InvalidAnalysis(line) => {
+                write!(f, "invalid water analysis line: \"{}\"", line)
+            }
+
+            Self::NoCommonElement(from, to) => {
+                write!(
+                    f,
+                    "\"{}\" and \"{}\" share no single element to convert by",
+                    from, to
+                )
+            }
+
+            Self::DiluteStock(formula) => {
+                write!(f, "stock of \"{}\" is more dilute than target", formula)
+            }
+
+            Self::NotHydrateForm(formula, substance) => {
+                write!(
+                    f,
+                    "\"{}\" is not hydrate form of \"{}\"",
+                    formula, substance
+                )
+            }
+
+            Self::UnknownGas(formula) => {
+                write!(f, "no van der Waals constants for \"{}\"", formula)
+            }
+
+            Self::OutOfRange(quantity, value) => {
+                write!(f, "{} is out of range: {}", quantity, value)
+            }
+
+            Self::UnknownSymbol(symbol) => {
+                write!(f, "unknown element symbol: \"{}\"", symbol)
+            }
+
+            Self::NotConverged(formula) => {
+                write!(
+                    f,
+                    "van der Waals molar volume of \"{}\" didn't converge",
+                    formula
+                )
+            }
         }
     }
 }
@@ -0,0 +1,54 @@
+use crate::fertilisers::Product;
+use crate::Compound;
+use std::collections::HashMap;
+
+/// A fertiliser defined by formula or by label
+#[derive(Clone, Debug, PartialEq)]
+pub enum Fertiliser {
+    Compound(Compound),
+    Product(Product),
+}
+
+impl Fertiliser {
+    /// list percents of element mass to fertiliser mass
+    pub fn element_percents(&self) -> HashMap<&'static str, f32> {
+        match self {
+            Self::Compound(compound) => compound.element_percents(),
+
+            Self::Product(product) => product.analysis().elements().clone(),
+        }
+    }
+
+    /// get percent of element mass to fertiliser mass
+    pub fn element_percent(&self, symbol: &str) -> f32 {
+        match self {
+            Self::Compound(compound) => compound
+                .element_percents()
+                .get(symbol)
+                .copied()
+                .unwrap_or(0.0),
+
+            Self::Product(product) => product.analysis().element_percent(symbol),
+        }
+    }
+
+    /// get compound if fertiliser is defined by formula
+    pub fn compound(&self) -> Option<&Compound> {
+        match self {
+            Self::Compound(compound) => Some(compound),
+            Self::Product(_) => None,
+        }
+    }
+}
+
+impl From<Compound> for Fertiliser {
+    fn from(compound: Compound) -> Self {
+        Self::Compound(compound)
+    }
+}
+
+impl From<Product> for Fertiliser {
+    fn from(product: Product) -> Self {
+        Self::Product(product)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::fertilisers::{element_to_oxide, label_oxide, Fertiliser, Product};
+use crate::Compound;
+use std::collections::HashMap;
+
+/// A guaranteed analysis of fertiliser
+///
+/// Contains elemental mass percents, reported on label oxide basis
+/// for elements labels express as oxides
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GuaranteedAnalysis {
+    elements: HashMap<&'static str, f32>,
+}
+
+impl GuaranteedAnalysis {
+    /// analysis of mixture of fertilisers, given masses of each in any unit
+    pub fn blend(parts: &[(Fertiliser, f32)]) -> Self {
+        let total: f32 = parts.iter().map(|(_, mass)| mass).sum();
+
+        let mut analysis = Self::default();
+
+        parts.iter().for_each(|(fertiliser, mass)| {
+            fertiliser
+                .element_percents()
+                .into_iter()
+                .for_each(|(symbol, percent)| analysis.add_percent(symbol, percent * mass / total));
+        });
+
+        analysis
+    }
+
+    pub(crate) fn add_percent(&mut self, symbol: &'static str, percent: f32) {
+        *self.elements.entry(symbol).or_insert(0.0) += percent;
+    }
+
+    /// get percent of element mass
+    pub fn element_percent(&self, symbol: &str) -> f32 {
+        self.elements.get(symbol).copied().unwrap_or(0.0)
+    }
+
+    /// get percent of element on label oxide basis, e.g. phosphorus as P2O5
+    pub fn oxide_percent(&self, symbol: &str) -> Option<f32> {
+        element_to_oxide(symbol, self.element_percent(symbol))
+    }
+
+    /// list elemental percents
+    pub fn elements(&self) -> &HashMap<&'static str, f32> {
+        &self.elements
+    }
+
+    /// list percents on label basis: oxides where labels use them, elements otherwise
+    pub fn label(&self) -> HashMap<&'static str, f32> {
+        self.elements
+            .iter()
+            .map(|(symbol, percent)| match label_oxide(symbol) {
+                Some(oxide) => (oxide, element_to_oxide(symbol, *percent).unwrap()),
+                None => (*symbol, *percent),
+            })
+            .collect()
+    }
+
+    /// get N-P2O5-K2O grade, like "13.9-0-46.6"
+    pub fn npk(&self) -> String {
+        [
+            self.element_percent("N"),
+            self.oxide_percent("P").unwrap(),
+            self.oxide_percent("K").unwrap(),
+        ]
+        .iter()
+        .map(|percent| format_grade(*percent))
+        .collect::<Vec<String>>()
+        .join("-")
+    }
+}
+
+impl From<&Compound> for GuaranteedAnalysis {
+    fn from(compound: &Compound) -> Self {
+        Self {
+            elements: compound.element_percents(),
+        }
+    }
+}
+
+impl From<&Product> for GuaranteedAnalysis {
+    fn from(product: &Product) -> Self {
+        product.analysis().clone()
+    }
+}
+
+fn format_grade(percent: f32) -> String {
+    let rounded = (percent * 10.).round() / 10.;
+
+    match rounded.fract() == 0.0 {
+        true => format!("{}", rounded as i32),
+        false => format!("{:.1}", rounded),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuaranteedAnalysis;
+
+    #[test]
+    fn compound_grade() {
+        let analysis = GuaranteedAnalysis::from(&crate::parse("KNO3").unwrap());
+
+        assert_eq!(analysis.npk(), "13.9-0-46.6");
+
+        let analysis = GuaranteedAnalysis::from(&crate::parse("NH4H2PO4").unwrap());
+
+        assert_eq!(analysis.npk(), "12.2-61.7-0");
+
+        assert!((analysis.label()["P2O5"] - 61.7).abs() < 0.1);
+    }
+
+    #[test]
+    fn blend_grade() {
+        let analysis = GuaranteedAnalysis::blend(&[
+            (crate::parse("KNO3").unwrap().into(), 50.),
+            (crate::parse("KH2PO4").unwrap().into(), 50.),
+        ]);
+
+        assert_eq!(analysis.npk(), "6.9-26.1-40.6");
+
+        let analysis = GuaranteedAnalysis::blend(&[
+            (crate::Product::from_label("13-0-46").unwrap().into(), 50.),
+            (crate::parse("KH2PO4").unwrap().into(), 50.),
+        ]);
+
+        assert_eq!(analysis.npk(), "6.5-26.1-40.3");
+    }
+}
@@ -0,0 +1,63 @@
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+/// oxides fertiliser labels express element contents as
+const LABEL_OXIDES: [(&str, &str); 7] = [
+    ("P", "P2O5"),
+    ("K", "K2O"),
+    ("Ca", "CaO"),
+    ("Mg", "MgO"),
+    ("S", "SO3"),
+    ("Na", "Na2O"),
+    ("Si", "SiO2"),
+];
+
+/// mass of oxide per mass of element in it
+static FACTORS: Lazy<HashMap<&'static str, f32>> = Lazy::new(|| {
+    LABEL_OXIDES
+        .iter()
+        .map(|(symbol, oxide)| {
+            let element = crate::parse(*symbol).unwrap();
+
+            let factor = element.element_factor(&crate::parse(*oxide).unwrap(), symbol);
+
+            (*symbol, factor.unwrap())
+        })
+        .collect()
+});
+
+/// get formula of oxide label expresses element as, e.g. "P2O5" for "P"
+pub fn label_oxide(symbol: &str) -> Option<&'static str> {
+    LABEL_OXIDES
+        .iter()
+        .find(|(element, _)| *element == symbol)
+        .map(|(_, oxide)| *oxide)
+}
+
+/// convert elemental content to label oxide basis
+pub fn element_to_oxide(symbol: &str, element_percent: f32) -> Option<f32> {
+    FACTORS.get(symbol).map(|factor| element_percent * factor)
+}
+
+/// convert label oxide content to elemental basis
+pub fn oxide_to_element(symbol: &str, oxide_percent: f32) -> Option<f32> {
+    FACTORS.get(symbol).map(|factor| oxide_percent / factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{element_to_oxide, label_oxide, oxide_to_element};
+
+    #[test]
+    fn conversion_factors() {
+        assert_eq!(label_oxide("P"), Some("P2O5"));
+
+        assert!((element_to_oxide("P", 1.).unwrap() - 2.2914).abs() < 0.001);
+
+        assert!((element_to_oxide("K", 1.).unwrap() - 1.2046).abs() < 0.001);
+
+        assert!((oxide_to_element("K", 60.).unwrap() - 49.81).abs() < 0.01);
+
+        assert_eq!(element_to_oxide("N", 1.), None);
+    }
+}
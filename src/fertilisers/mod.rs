@@ -0,0 +1,9 @@
+mod fertiliser;
+mod guaranteed_analysis;
+mod label_oxide;
+mod product;
+
+pub use fertiliser::Fertiliser;
+pub use guaranteed_analysis::GuaranteedAnalysis;
+pub use label_oxide::{element_to_oxide, label_oxide, oxide_to_element};
+pub use product::Product;
@@ -0,0 +1,185 @@
+use crate::fertilisers::{label_oxide, oxide_to_element, GuaranteedAnalysis};
+use crate::{Error, PERIODIC_TABLE};
+
+/// A commercial fertiliser product
+///
+/// Defined by label percents instead of formula
+#[derive(Clone, Debug, PartialEq)]
+pub struct Product {
+    name: Option<String>,
+    analysis: GuaranteedAnalysis,
+}
+
+impl Product {
+    pub fn new(name: Option<&str>) -> Self {
+        Self {
+            name: name.map(|name| name.to_string()),
+            analysis: GuaranteedAnalysis::default(),
+        }
+    }
+
+    /// parse label like "13-0-46" or "Calcinit 15.5-0-0 + 19% Ca",
+    /// grade is N-P2O5-K2O, additions are elements or label oxides
+    pub fn from_label(label: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidLabel(label.to_string());
+
+        let mut parts = label.split('+');
+
+        let mut words: Vec<&str> = parts.next().unwrap_or("").split_whitespace().collect();
+
+        let grade: Vec<f32> = words
+            .pop()
+            .ok_or_else(invalid)?
+            .split('-')
+            .map(|value| value.parse::<f32>().map_err(|_| invalid()))
+            .collect::<Result<_, _>>()?;
+
+        if grade.len() != 3 {
+            return Err(invalid());
+        }
+
+        let name = words.join(" ");
+
+        let mut product = Self::new(match name.is_empty() {
+            true => None,
+            false => Some(&name),
+        });
+
+        product.add_element_percent("N", grade[0])?;
+        product.add_oxide_percent("P", grade[1])?;
+        product.add_oxide_percent("K", grade[2])?;
+
+        for part in parts {
+            let part = part.trim();
+
+            let split = part
+                .find(|char: char| !char.is_ascii_digit() && char != '.')
+                .ok_or_else(invalid)?;
+
+            let percent: f32 = part[..split].parse().map_err(|_| invalid())?;
+
+            let formula = part[split..].trim_start_matches('%').trim();
+
+            product
+                .add_percent(formula, percent)
+                .map_err(|_| invalid())?;
+        }
+
+        Ok(product)
+    }
+
+    /// add percent of element or label oxide, like "Ca" or "CaO"
+    pub fn add_percent(&mut self, formula: &str, percent: f32) -> Result<(), Error> {
+        if PERIODIC_TABLE.lookup(formula).is_some() {
+            return self.add_element_percent(formula, percent);
+        }
+
+        let compound = crate::parse(formula)?;
+
+        match compound
+            .components()
+            .keys()
+            .find(|symbol| label_oxide(symbol) == Some(formula))
+        {
+            Some(symbol) => self.add_oxide_percent(symbol, percent),
+            None => Err(Error::InvalidLabel(formula.to_string())),
+        }
+    }
+
+    /// add elemental percent within 0-100, zero percents are skipped
+    pub fn add_element_percent(&mut self, symbol: &str, percent: f32) -> Result<(), Error> {
+        let chemical_element = PERIODIC_TABLE
+            .lookup(symbol)
+            .ok_or(Error::UnknownSymbol(symbol.to_string()))?;
+
+        check_percent(symbol, percent)?;
+
+        if percent != 0.0 {
+            self.analysis
+                .add_percent(chemical_element.symbol(), percent);
+        }
+
+        Ok(())
+    }
+
+    /// add percent of element on label oxide basis, like potassium as K2O
+    pub fn add_oxide_percent(&mut self, symbol: &str, percent: f32) -> Result<(), Error> {
+        check_percent(symbol, percent)?;
+
+        let element_percent =
+            oxide_to_element(symbol, percent).ok_or(Error::InvalidLabel(symbol.to_string()))?;
+
+        self.add_element_percent(symbol, element_percent)
+    }
+
+    /// get name of product
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// get guaranteed analysis of product
+    pub fn analysis(&self) -> &GuaranteedAnalysis {
+        &self.analysis
+    }
+}
+
+fn check_percent(symbol: &str, percent: f32) -> Result<(), Error> {
+    match (0.0..=100.0).contains(&percent) {
+        true => Ok(()),
+        false => Err(Error::OutOfRange(format!("percent of {}", symbol), percent)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Product;
+    use crate::Error;
+
+    #[test]
+    fn label_parsing() {
+        let product = Product::from_label("13-0-46").unwrap();
+
+        assert_eq!(product.name(), None);
+
+        assert_eq!(product.analysis().npk(), "13-0-46");
+
+        assert!((product.analysis().element_percent("K") - 38.19).abs() < 0.01);
+
+        let product = Product::from_label("Calcinit 15.5-0-0 + 19% Ca").unwrap();
+
+        assert_eq!(product.name(), Some("Calcinit"));
+
+        assert_eq!(product.analysis().element_percent("Ca"), 19.);
+
+        let product = Product::from_label("Kieserite 0-0-0 + 25% MgO + 50% SO3").unwrap();
+
+        assert!((product.analysis().element_percent("Mg") - 15.08).abs() < 0.01);
+
+        assert!((product.analysis().element_percent("S") - 20.02).abs() < 0.01);
+    }
+
+    #[test]
+    fn invalid_labels() {
+        assert!(Product::from_label("13-0").is_err());
+
+        assert!(Product::from_label("13-0-46 + 5% NO3").is_err());
+
+        assert!(Product::from_label("N-P-K").is_err());
+
+        assert!(matches!(
+            Product::from_label("120-0-0"),
+            Err(Error::OutOfRange(name, _)) if name == "percent of N"
+        ));
+
+        assert!(Product::from_label("0-150-0").is_err());
+
+        assert!(Product::new(None).add_element_percent("N", -1.).is_err());
+
+        assert!(Product::new(None).add_percent("K2O", 101.).is_err());
+
+        assert!(matches!(
+            Product::new(None).add_element_percent("Xx", 1.),
+            Err(Error::UnknownSymbol(symbol)) if symbol == "Xx"
+        ));
+    }
+}
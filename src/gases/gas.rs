@@ -0,0 +1,177 @@
+use crate::gases::van_der_waals;
+use crate::{Compound, Error, GAS_CONSTANT};
+
+/// iterations of Newton's method for van der Waals molar volume
+const ITERATIONS: usize = 100;
+
+/// relative step of Newton's method regarded as converged
+const TOLERANCE: f64 = 1e-9;
+
+/// A gas of compound
+///
+/// Takes temperatures in °C and pressures in kPa, gives volumes in litres
+/// and densities in g/L. Ideal by default, with optional van der Waals
+/// correction for built-in set of common gases
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gas {
+    compound: Compound,
+    constants: Option<(f32, f32)>,
+}
+
+impl Gas {
+    pub fn new(compound: Compound) -> Self {
+        Self {
+            compound,
+            constants: None,
+        }
+    }
+
+    /// create ideal gas of compound defined by formula
+    pub fn from_formula(formula: &str) -> Result<Self, Error> {
+        Ok(Self::new(crate::parse(formula)?))
+    }
+
+    /// apply van der Waals correction with constants of gas
+    pub fn van_der_waals(mut self) -> Result<Self, Error> {
+        let formula = self.compound.substance().to_string();
+
+        self.constants = Some(van_der_waals::lookup(&formula).ok_or(Error::UnknownGas(formula))?);
+
+        Ok(self)
+    }
+
+    /// get compound of gas
+    pub fn compound(&self) -> &Compound {
+        &self.compound
+    }
+
+    /// get molar volume in L/mol
+    pub fn molar_volume(&self, temperature: f32, pressure: f32) -> Result<f32, Error> {
+        if temperature <= -273.15 {
+            return Err(Error::OutOfRange("temperature".to_string(), temperature));
+        }
+
+        if pressure <= 0.0 {
+            return Err(Error::OutOfRange("pressure".to_string(), pressure));
+        }
+
+        // L·kPa/(mol·K) equals J/(mol·K)
+        let energy = GAS_CONSTANT * (temperature + 273.15);
+
+        let ideal = energy / pressure;
+
+        let (a, b) = match self.constants {
+            Some(constants) => constants,
+            None => return Ok(ideal),
+        };
+
+        // solves (P + a/V²)(V - b) = RT in double precision, residual is
+        // negative at excluded volume and positive at ideal volume plus it
+        let (pressure, energy, a, b) = (pressure as f64, energy as f64, a as f64, b as f64);
+
+        let (mut low, mut high) = (b, ideal as f64 + b);
+
+        let mut volume = high;
+
+        for _ in 0..ITERATIONS {
+            let residual = (pressure + a / volume.powi(2)) * (volume - b) - energy;
+
+            match residual < 0.0 {
+                true => low = volume,
+                false => high = volume,
+            }
+
+            let derivative = pressure - a / volume.powi(2) + 2. * a * b / volume.powi(3);
+
+            let mut next = volume - residual / derivative;
+
+            // flat isotherm near critical point or step out of bracket bisects it
+            if derivative.abs() < f64::EPSILON || !(next > low && next < high) {
+                next = (low + high) / 2.;
+            }
+
+            if (next - volume).abs() <= TOLERANCE * next {
+                return Ok(next as f32);
+            }
+
+            volume = next;
+        }
+
+        Err(Error::NotConverged(self.compound.substance().to_string()))
+    }
+
+    /// get density in g/L
+    pub fn density(&self, temperature: f32, pressure: f32) -> Result<f32, Error> {
+        Ok(self.molar_mass() / self.molar_volume(temperature, pressure)?)
+    }
+
+    /// get volume in litres of mass of gas in grams
+    pub fn volume(&self, mass: f32, temperature: f32, pressure: f32) -> Result<f32, Error> {
+        Ok(mass / self.molar_mass() * self.molar_volume(temperature, pressure)?)
+    }
+
+    /// molar mass of pure gas, as purity of reagent is not a property of gas
+    fn molar_mass(&self) -> f32 {
+        self.compound.molar_mass() * self.compound.purity() / 100.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gas;
+    use crate::GAS_CONSTANT;
+
+    #[test]
+    fn ideal_gas() {
+        let nitrogen = Gas::from_formula("N2").unwrap();
+
+        assert!((nitrogen.molar_volume(0., 101.325).unwrap() - 22.414).abs() < 0.01);
+
+        assert!((nitrogen.density(0., 101.325).unwrap() - 1.2498).abs() < 0.001);
+
+        assert!((nitrogen.volume(28.014, 25., 101.325).unwrap() - 24.465).abs() < 0.01);
+    }
+
+    #[test]
+    fn van_der_waals_correction() {
+        let carbon_dioxide = Gas::from_formula("CO2").unwrap().van_der_waals().unwrap();
+
+        // measured density at 0 °C and 1 atm is 1.977 g/L, ideal gas gives 1.964 g/L
+        assert!((carbon_dioxide.density(0., 101.325).unwrap() - 1.977).abs() < 0.005);
+
+        assert!(Gas::from_formula("SF6").unwrap().van_der_waals().is_err());
+    }
+
+    #[test]
+    fn impure_compound() {
+        let carbon_dioxide = Gas::new(crate::parse("CO2").unwrap().with_purity(90.).unwrap());
+
+        assert!((carbon_dioxide.density(0., 101.325).unwrap() - 1.964).abs() < 0.005);
+
+        assert!((carbon_dioxide.volume(44.01, 0., 101.325).unwrap() - 22.414).abs() < 0.01);
+    }
+
+    #[test]
+    fn near_critical_point() {
+        let carbon_dioxide = Gas::from_formula("CO2").unwrap().van_der_waals().unwrap();
+
+        let (a, b) = carbon_dioxide.constants.unwrap();
+
+        [(31., 7380.), (0., 5000.), (-50., 5000.)]
+            .iter()
+            .for_each(|(temperature, pressure)| {
+                let volume = carbon_dioxide
+                    .molar_volume(*temperature, *pressure)
+                    .unwrap();
+
+                let residual = (pressure + a / volume.powi(2)) * (volume - b)
+                    - GAS_CONSTANT * (temperature + 273.15);
+
+                assert!(residual.abs() < 0.1);
+            });
+
+        assert!(carbon_dioxide.molar_volume(25., 0.).is_err());
+
+        assert!(carbon_dioxide.molar_volume(-300., 100.).is_err());
+    }
+}
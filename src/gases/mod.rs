@@ -0,0 +1,4 @@
+mod gas;
+mod van_der_waals;
+
+pub use gas::Gas;
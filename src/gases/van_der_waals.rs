@@ -0,0 +1,34 @@
+/// van der Waals constants of common gases: a in L²·bar/mol², b in L/mol
+const CONSTANTS: [(&str, f32, f32); 23] = [
+    ("H2", 0.2476, 0.02661),
+    ("He", 0.03457, 0.0237),
+    ("Ne", 0.2135, 0.01709),
+    ("Ar", 1.355, 0.03201),
+    ("Kr", 2.349, 0.03978),
+    ("Xe", 4.25, 0.05105),
+    ("N2", 1.37, 0.0387),
+    ("O2", 1.382, 0.03186),
+    ("Cl2", 6.579, 0.05622),
+    ("CO", 1.505, 0.03985),
+    ("CO2", 3.64, 0.04267),
+    ("NO", 1.358, 0.02789),
+    ("NO2", 5.354, 0.04424),
+    ("N2O", 3.832, 0.04415),
+    ("NH3", 4.225, 0.0371),
+    ("H2O", 5.536, 0.03049),
+    ("H2S", 4.49, 0.04287),
+    ("SO2", 6.803, 0.05636),
+    ("CH4", 2.283, 0.04278),
+    ("C2H2", 4.516, 0.0522),
+    ("C2H4", 4.612, 0.05821),
+    ("C2H6", 5.562, 0.0638),
+    ("C3H8", 8.779, 0.08445),
+];
+
+/// get constants a in L²·kPa/mol² and b in L/mol of gas formula
+pub(crate) fn lookup(formula: &str) -> Option<(f32, f32)> {
+    CONSTANTS
+        .iter()
+        .find(|(gas, _, _)| *gas == formula)
+        .map(|(_, a, b)| (a * 100., *b))
+}
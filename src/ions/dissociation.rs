@@ -0,0 +1,344 @@
+use crate::chemistry::ChemicalElement;
+use crate::compounds::solve;
+use crate::ions::{library, Ion};
+use crate::tokens::{Component, Element, Group, Substance};
+use crate::Error;
+
+/// A dissociation of substance into ions
+///
+/// Polyatomic ions are detected from parsed groups and from runs of
+/// elements matching the library, charges of monatomic ions are solved
+/// from charge neutrality
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dissociation {
+    ions: Vec<Ion>,
+    water: usize,
+}
+
+#[derive(Clone, Debug)]
+enum Segment {
+    Polyatomic(&'static Ion, usize),
+    Monatomic(ChemicalElement, usize),
+}
+
+impl Dissociation {
+    /// list ions with counts per formula unit
+    pub fn ions(&self) -> &[Ion] {
+        &self.ions
+    }
+
+    /// get count of water molecules of crystallisation released
+    pub fn water(&self) -> usize {
+        self.water
+    }
+
+    fn add_ion(&mut self, ion: Ion) {
+        match self
+            .ions
+            .iter_mut()
+            .find(|added| added.formula() == ion.formula())
+        {
+            Some(added) => added.add_count(ion.count()),
+            None => self.ions.push(ion),
+        }
+    }
+}
+
+impl TryFrom<&Substance> for Dissociation {
+    type Error = Error;
+
+    fn try_from(substance: &Substance) -> Result<Self, Self::Error> {
+        let coefficient = substance.coefficient();
+
+        let charge = substance.charge() / coefficient as i32;
+
+        let elements = Group::from(substance.composition().to_vec(), 1).elements();
+
+        if charge == 0 && library::is_weak_acid(&elements) {
+            return Err(Error::Undissociable(substance.to_string()));
+        }
+
+        let mut segmentations = vec![];
+
+        segment(substance.composition(), 0, &mut vec![], &mut segmentations);
+
+        // the fewest segments means the longest polyatomic ions, and
+        // polyprotic acids give up only their first proton
+        segmentations.sort_by_key(|segments| (segments.len(), protons(segments)));
+
+        for segments in segmentations {
+            let charges = match monatomic_charges(&segments, charge) {
+                Some(charges) => charges,
+                None => continue,
+            };
+
+            let mut dissociation = Self {
+                ions: vec![],
+                water: substance
+                    .hydrate()
+                    .map(|hydrate| hydrate.coefficient() * coefficient)
+                    .unwrap_or(0),
+            };
+
+            segments
+                .iter()
+                .zip(charges)
+                .for_each(|(segment, charge)| match segment {
+                    Segment::Polyatomic(ion, count) => {
+                        dissociation.add_ion((*ion).clone().with_count(count * coefficient));
+                    }
+
+                    Segment::Monatomic(element, count) => {
+                        let substance = Substance::from(
+                            1,
+                            vec![Component::Element(Element::new(*element, 1))],
+                            None,
+                        )
+                        .with_charge(charge);
+
+                        dissociation.add_ion(Ion::new(None, substance, count * coefficient));
+                    }
+                });
+
+            return Ok(dissociation);
+        }
+
+        Err(Error::Undissociable(substance.to_string()))
+    }
+}
+
+impl std::fmt::Display for Dissociation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut species: Vec<String> = self.ions.iter().map(|ion| ion.to_string()).collect();
+
+        match self.water {
+            0 => {}
+            1 => species.push("H2O".to_string()),
+            water => species.push(format!("{} H2O", water)),
+        }
+
+        write!(f, "{}", species.join(" + "))
+    }
+}
+
+/// an ion of species written without charge takes the charge of
+/// library ion or single element, species of neither stay neutral
+pub(crate) fn species(substance: Substance) -> Ion {
+    if substance.charge() != 0 {
+        let name = library::lookup(&substance.elements()).and_then(|ion| ion.name());
+
+        return Ion::new(name, substance, 1);
+    }
+
+    if let Some(ion) = library::lookup(&substance.elements()) {
+        return ion.clone();
+    }
+
+    let charge = match substance.composition() {
+        [Component::Element(element)] if element.subscript() == 1 => {
+            ionic_charges(element.chemical_element()).first().copied()
+        }
+        _ => None,
+    };
+
+    match charge {
+        Some(charge) => Ion::new(None, substance.with_charge(charge), 1),
+        None => Ion::new(None, substance, 1),
+    }
+}
+
+/// collects every way to split components into library ions and single elements
+fn segment(
+    components: &[Component],
+    index: usize,
+    segments: &mut Vec<Segment>,
+    segmentations: &mut Vec<Vec<Segment>>,
+) {
+    let component = match components.get(index) {
+        Some(component) => component,
+        None => {
+            segmentations.push(segments.clone());
+
+            return;
+        }
+    };
+
+    match component {
+        Component::Group(group) => {
+            let elements = Group::from(group.composition().to_vec(), 1).elements();
+
+            if let Some(ion) = library::lookup(&elements) {
+                segments.push(Segment::Polyatomic(ion, group.subscript()));
+
+                segment(components, index + 1, segments, segmentations);
+
+                segments.pop();
+            }
+        }
+
+        Component::Element(element) => {
+            segments.push(Segment::Monatomic(
+                element.chemical_element(),
+                element.subscript(),
+            ));
+
+            segment(components, index + 1, segments, segmentations);
+
+            segments.pop();
+
+            polyatomic_runs(components, index, vec![], segments, segmentations);
+
+            // leading hydrogen splits into protons and ion like H2PO4-
+            if element.chemical_element() == ChemicalElement::Hydrogen {
+                for count in 1..element.subscript() {
+                    let rest = Element::new(ChemicalElement::Hydrogen, element.subscript() - count);
+
+                    segments.push(Segment::Monatomic(ChemicalElement::Hydrogen, count));
+
+                    polyatomic_runs(components, index + 1, vec![rest], segments, segmentations);
+
+                    segments.pop();
+                }
+            }
+        }
+    }
+}
+
+/// continues segmentations with library ions of elements starting at index
+fn polyatomic_runs(
+    components: &[Component],
+    index: usize,
+    mut elements: Vec<Element>,
+    segments: &mut Vec<Segment>,
+    segmentations: &mut Vec<Vec<Segment>>,
+) {
+    for (offset, component) in components[index..].iter().enumerate() {
+        match component {
+            Component::Element(element) => elements.push(*element),
+            Component::Group(_) => break,
+        }
+
+        if let Some(ion) = library::lookup(&elements) {
+            segments.push(Segment::Polyatomic(ion, 1));
+
+            segment(components, index + offset + 1, segments, segmentations);
+
+            segments.pop();
+        }
+    }
+}
+
+/// counts hydrogen ions of segments
+fn protons(segments: &[Segment]) -> usize {
+    segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Monatomic(ChemicalElement::Hydrogen, count) => *count,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// solves charges of segments, keeping charges of polyatomic ions
+fn monatomic_charges(segments: &[Segment], charge: i32) -> Option<Vec<i32>> {
+    let counts: Vec<usize> = segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Polyatomic(_, count) => *count,
+            Segment::Monatomic(_, count) => *count,
+        })
+        .collect();
+
+    let candidates: Vec<Vec<i32>> = segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Polyatomic(ion, _) => vec![ion.charge()],
+            Segment::Monatomic(element, _) => ionic_charges(*element),
+        })
+        .collect();
+
+    solve(&counts, &candidates, charge)
+        .into_iter()
+        .min_by_key(|(_, rank)| *rank)
+        .map(|(charges, _)| charges)
+}
+
+/// charges single elements take in aqueous solution
+fn ionic_charges(element: ChemicalElement) -> Vec<i32> {
+    match element {
+        ChemicalElement::Hydrogen => vec![1],
+        ChemicalElement::Fluorine
+        | ChemicalElement::Chlorine
+        | ChemicalElement::Bromine
+        | ChemicalElement::Iodine => vec![-1],
+        ChemicalElement::Sulfur | ChemicalElement::Selenium => vec![-2],
+        element if element.is_metal() => element
+            .oxidation_states()
+            .iter()
+            .filter(|state| **state > 0)
+            .copied()
+            .collect(),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dissociate;
+
+    #[test]
+    fn salts() {
+        assert_eq!(dissociate("Ca(NO3)2").unwrap().to_string(), "Ca2+ + 2 NO3-");
+
+        assert_eq!(
+            dissociate("MgSO4*7H2O").unwrap().to_string(),
+            "Mg2+ + SO4 2- + 7 H2O"
+        );
+
+        assert_eq!(dissociate("NH4NO3").unwrap().to_string(), "NH4+ + NO3-");
+
+        assert_eq!(dissociate("KH2PO4").unwrap().to_string(), "K+ + H2PO4-");
+
+        assert_eq!(dissociate("FeCl3").unwrap().to_string(), "Fe3+ + 3 Cl-");
+
+        assert_eq!(
+            dissociate("C14H18N3O10Fe(NH4)2").unwrap().to_string(),
+            "C14H18N3O10Fe 2- + 2 NH4+"
+        );
+    }
+
+    #[test]
+    fn counts_and_charges() {
+        let dissociation = dissociate("2Al2(SO4)3").unwrap();
+
+        let ions: Vec<(&str, i32, usize)> = dissociation
+            .ions()
+            .iter()
+            .map(|ion| (ion.formula(), ion.charge(), ion.count()))
+            .collect();
+
+        assert_eq!(ions, vec![("Al3+", 3, 4), ("SO4 2-", -2, 6)]);
+    }
+
+    #[test]
+    fn non_electrolytes() {
+        assert!(dissociate("C6H12O6").is_err());
+
+        assert!(dissociate("CO(NH2)2").is_err());
+
+        assert!(dissociate("H2O").is_err());
+    }
+
+    #[test]
+    fn acids() {
+        assert_eq!(dissociate("HNO3").unwrap().to_string(), "H+ + NO3-");
+
+        assert_eq!(dissociate("H3PO4").unwrap().to_string(), "H+ + H2PO4-");
+
+        assert_eq!(dissociate("H2SO4").unwrap().to_string(), "H+ + HSO4-");
+
+        assert!(dissociate("H3BO3").is_err());
+
+        assert!(dissociate("H4SiO4").is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use crate::tokens::Substance;
+use crate::Compound;
+
+/// An ion in solution
+///
+/// Contains formula and charge of ion and its count per formula unit
+/// of dissociated substance
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ion {
+    name: Option<&'static str>,
+    formula: String,
+    compound: Compound,
+    count: usize,
+}
+
+impl Ion {
+    pub(crate) fn new(name: Option<&'static str>, substance: Substance, count: usize) -> Self {
+        Self {
+            name,
+            formula: substance.to_string(),
+            compound: Compound::from(substance),
+            count,
+        }
+    }
+
+    pub(crate) fn with_count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub(crate) fn add_count(&mut self, count: usize) {
+        self.count += count;
+    }
+
+    /// get common name of polyatomic ion
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// get formula of ion with charge, e.g. "SO4 2-"
+    pub fn formula(&self) -> &str {
+        &self.formula
+    }
+
+    /// get charge of ion
+    pub fn charge(&self) -> i32 {
+        self.compound.charge()
+    }
+
+    /// get count of ions per formula unit
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// get parsed compound of ion
+    pub fn compound(&self) -> &Compound {
+        &self.compound
+    }
+}
+
+impl std::fmt::Display for Ion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.count {
+            1 => write!(f, "{}", self.formula),
+            count => write!(f, "{} {}", count, self.formula),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::ions::Ion;
+use crate::tokens::Element;
+use once_cell::sync::Lazy;
+
+const POLYATOMIC_IONS: [(&str, &str); 47] = [
+    ("NH4+", "ammonium"),
+    ("H3O+", "hydronium"),
+    ("NO3-", "nitrate"),
+    ("NO2-", "nitrite"),
+    ("SO4 2-", "sulfate"),
+    ("HSO4-", "hydrogen sulfate"),
+    ("SO3 2-", "sulfite"),
+    ("HSO3-", "hydrogen sulfite"),
+    ("S2O3 2-", "thiosulfate"),
+    ("PO4 3-", "phosphate"),
+    ("HPO4 2-", "hydrogen phosphate"),
+    ("H2PO4-", "dihydrogen phosphate"),
+    ("P2O7 4-", "pyrophosphate"),
+    ("PO3 3-", "phosphite"),
+    ("HPO3 2-", "hydrogen phosphite"),
+    ("CO3 2-", "carbonate"),
+    ("HCO3-", "bicarbonate"),
+    ("OH-", "hydroxide"),
+    ("CH3COO-", "acetate"),
+    ("C2H3O2-", "acetate"),
+    ("C2O4 2-", "oxalate"),
+    ("HCOO-", "formate"),
+    ("C6H5O7 3-", "citrate"),
+    ("ClO-", "hypochlorite"),
+    ("ClO2-", "chlorite"),
+    ("ClO3-", "chlorate"),
+    ("ClO4-", "perchlorate"),
+    ("BrO3-", "bromate"),
+    ("IO3-", "iodate"),
+    ("MnO4-", "permanganate"),
+    ("CrO4 2-", "chromate"),
+    ("Cr2O7 2-", "dichromate"),
+    ("MoO4 2-", "molybdate"),
+    ("WO4 2-", "tungstate"),
+    ("SiO3 2-", "metasilicate"),
+    ("SiO4 4-", "orthosilicate"),
+    ("BO3 3-", "borate"),
+    ("B4O7 2-", "tetraborate"),
+    ("AsO4 3-", "arsenate"),
+    ("SeO4 2-", "selenate"),
+    ("CN-", "cyanide"),
+    ("SCN-", "thiocyanate"),
+    ("C10H12N2O8Fe-", "iron EDTA chelate"),
+    ("C14H18N3O10Fe 2-", "iron DTPA chelate"),
+    ("C10H12N2O8Zn 2-", "zinc EDTA chelate"),
+    ("C10H12N2O8Mn 2-", "manganese EDTA chelate"),
+    ("C10H12N2O8Cu 2-", "copper EDTA chelate"),
+];
+
+/// acids too weak to dissociate noticeably in water
+const WEAK_ACIDS: [&str; 5] = ["H3BO3", "H4SiO4", "H2SiO3", "H2CO3", "HCN"];
+
+static LIBRARY: Lazy<Vec<Ion>> = Lazy::new(|| {
+    POLYATOMIC_IONS
+        .iter()
+        .map(|(formula, name)| {
+            let substance = crate::parse_substance(formula).unwrap();
+
+            Ion::new(Some(name), substance, 1)
+        })
+        .collect()
+});
+
+static SEQUENCES: Lazy<Vec<Vec<Element>>> = Lazy::new(|| {
+    POLYATOMIC_IONS
+        .iter()
+        .map(|(formula, _)| crate::parse_substance(formula).unwrap().elements())
+        .collect()
+});
+
+/// finds polyatomic ion written with exactly the same sequence of elements
+pub(crate) fn lookup(elements: &[Element]) -> Option<&'static Ion> {
+    SEQUENCES
+        .iter()
+        .position(|sequence| sequence == elements)
+        .map(|index| &LIBRARY[index])
+}
+
+static WEAK_ACID_SEQUENCES: Lazy<Vec<Vec<Element>>> = Lazy::new(|| {
+    WEAK_ACIDS
+        .iter()
+        .map(|formula| crate::parse_substance(formula).unwrap().elements())
+        .collect()
+});
+
+/// checks if elements are of weak acid, like boric acid
+pub(crate) fn is_weak_acid(elements: &[Element]) -> bool {
+    WEAK_ACID_SEQUENCES
+        .iter()
+        .any(|sequence| sequence == elements)
+}
+
+/// list library of common polyatomic ions
+pub fn polyatomic_ions() -> &'static [Ion] {
+    &LIBRARY
+}
@@ -0,0 +1,9 @@
+mod dissociation;
+mod ion;
+mod library;
+
+pub(crate) use dissociation::species;
+pub use dissociation::Dissociation;
+pub use ion::Ion;
+pub(crate) use library::lookup;
+pub use library::polyatomic_ions;
@@ -63,30 +63,72 @@
 //! ```
 
 mod chemistry;
+mod combustion;
 mod compounds;
+mod concentrations;
 mod error;
+mod fertilisers;
+mod gases;
+mod ions;
 mod parser;
+mod solutions;
 mod tokenizer;
 mod tokens;
 
 pub use chemistry::ChemicalElement;
-pub use compounds::{Component, Compound};
+pub use combustion::{FlueGas, Fuel};
+pub use compounds::{
+    decomposition, volatile_count, Component, Compound, DecompositionStep, Fraction,
+    GroupComponent, Mixture, MixtureComponent, NitrogenForm, OxidationState, OxidationStates, Salt,
+    SaltBuilder,
+};
+pub use concentrations::{Concentration, ConcentrationUnit, Preparation, Solute};
 pub use error::Error;
+pub use fertilisers::{
+    element_to_oxide, label_oxide, oxide_to_element, Fertiliser, GuaranteedAnalysis, Product,
+};
+pub use gases::Gas;
+pub use ions::{polyatomic_ions, Dissociation, Ion};
 use once_cell::sync::Lazy;
 use parser::Parser;
-pub use tokens::Element;
+pub use solutions::{
+    saturation, solubility, Alkalinity, Conductivity, ElementConcentration, Hardness, IonBalance,
+    IonConcentration, Recipe, RecipeSolver, Saturation, SolubilityWarning, Solution, StockPlan,
+    StockPlanner, StockTank, WaterAnalysis,
+};
+pub use tokens::{Component as SubstanceComponent, Element, Group, Hydrate, Substance};
 
-static PERIODIC_TABLE: Lazy<chemistry::Table> = Lazy::new(|| chemistry::Table::new());
+static PERIODIC_TABLE: Lazy<chemistry::Table> = Lazy::new(chemistry::Table::new);
+
+/// gas constant in J/(mol·K)
+const GAS_CONSTANT: f32 = 8.314;
 
 /// A function takes raw formula string and produce compound or error
 pub fn parse<'a>(formula: impl Into<&'a str>) -> Result<Compound, Error> {
-    let mut parser = Parser::new(&PERIODIC_TABLE, formula.into());
-
-    let substance = parser.parse()?;
+    let substance = parse_substance(formula.into())?;
 
     Ok(Compound::from(substance))
 }
 
+/// A function takes raw formula string and splits substance into ions
+pub fn dissociate<'a>(formula: impl Into<&'a str>) -> Result<Dissociation, Error> {
+    parse(formula)?.dissociate()
+}
+
+/// A function re-expresses concentration of one formula as another sharing
+/// common element, e.g. nitrate "as NO3" to "as N" or phosphate "PO4" to "P2O5"
+pub fn express_as<'a>(
+    concentration: f32,
+    from: impl Into<&'a str>,
+    to: impl Into<&'a str>,
+) -> Result<f32, Error> {
+    Ok(concentration * parse(from)?.expressed_as(&parse(to)?)?)
+}
+
+pub(crate) fn parse_substance(formula: &str) -> Result<tokens::Substance, Error> {
+    Parser::new(&PERIODIC_TABLE, formula).parse()
+}
+
 #[cfg(test)]
 mod tests {
     use super::parse;
@@ -29,10 +29,20 @@ impl<'a> Parser<'a> {
 
         substance.add_coefficient(self.coefficient()?);
 
+        let mut components = vec![];
+
         while let Some(component) = self.component()? {
-            substance.add_component(component);
+            components.push(component);
+        }
+
+        if let Some(Token::Caret | Token::Space | Token::Plus | Token::Minus) = self.peek() {
+            substance.add_charge(self.charge(&mut components)?);
         }
 
+        components
+            .into_iter()
+            .for_each(|component| substance.add_component(component));
+
         if let Some(Token::Asterisk) = self.peek() {
             substance.add_hydrate(self.hydrate()?);
         }
@@ -78,7 +88,7 @@ impl<'a> Parser<'a> {
 
         group.add_subscript(self.subscript()?);
 
-        return Ok(group);
+        Ok(group)
     }
 
     fn element(&mut self, value: &'a str) -> Result<Element, Error> {
@@ -96,7 +106,7 @@ impl<'a> Parser<'a> {
 
         let subscript = self.subscript()?;
 
-        return Ok(Element::new(chemical_element, subscript));
+        Ok(Element::new(chemical_element, subscript))
     }
 
     fn hydrate(&mut self) -> Result<Hydrate, Error> {
@@ -115,6 +125,88 @@ impl<'a> Parser<'a> {
         Ok(hydrate)
     }
 
+    /// parses ionic charge
+    ///
+    /// when single sign directly follows a single element (e.g. "Ca2+", "O2-"),
+    /// its subscript is treated as the charge magnitude. it makes "I3-" iodine
+    /// 3- rather than triiodide, so homoatomic ions are written with caret
+    /// or space: "I3^-", "N3 -", "O2^-"
+    fn charge(&mut self, components: &mut [Component]) -> Result<i32, Error> {
+        let magnitude = match self.peek() {
+            Some(Token::Caret) => {
+                self.consume(Token::Caret)?;
+
+                Some(self.coefficient()?)
+            }
+
+            Some(Token::Space) => {
+                let position = self.tokenizer.cursor() - 1;
+
+                self.consume(Token::Space)?;
+
+                // trailing space is not a charge
+                if self.peek().is_none() {
+                    return Err(Error::UnexpectedToken(" ".to_string(), position));
+                }
+
+                Some(self.coefficient()?)
+            }
+
+            _ => None,
+        };
+
+        let (sign, repeats) = self.sign()?;
+
+        if let Some(magnitude) = magnitude {
+            if repeats > 1 {
+                return Err(Error::UnexpectedToken(
+                    if sign > 0 { "+" } else { "-" }.to_string(),
+                    self.tokenizer.cursor(),
+                ));
+            }
+
+            return Ok(sign * magnitude as i32);
+        }
+
+        if repeats == 1 {
+            if let [Component::Element(element)] = components {
+                if element.subscript() > 1 {
+                    let charge = sign * element.subscript() as i32;
+
+                    *element = Element::new(element.chemical_element(), 1);
+
+                    return Ok(charge);
+                }
+            }
+        }
+
+        Ok(sign * repeats)
+    }
+
+    fn sign(&mut self) -> Result<(i32, i32), Error> {
+        let (sign, token) = match self.peek() {
+            Some(Token::Plus) => (1, Token::Plus),
+            Some(Token::Minus) => (-1, Token::Minus),
+            Some(token) => {
+                return Err(Error::UnexpectedToken(
+                    token.value().to_string(),
+                    self.tokenizer.cursor(),
+                ))
+            }
+            None => return Err(Error::UnexpectedEnd("+".to_string())),
+        };
+
+        let mut repeats = 0;
+
+        while self.peek() == Some(&token) {
+            self.consume(token)?;
+
+            repeats += 1;
+        }
+
+        Ok((sign, repeats))
+    }
+
     fn coefficient(&mut self) -> Result<usize, Error> {
         let coefficient = match self.peek() {
             Some(Token::Number(value)) => {
@@ -179,6 +271,7 @@ mod tests {
     use super::Parser;
     use crate::chemistry::Table;
     use crate::tokens::{Component, Element, Group, Hydrate, Substance};
+    use crate::Error;
 
     #[test]
     fn parser_single_element() {
@@ -307,4 +400,100 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn parser_charge() {
+        let table = Table::new();
+
+        assert_eq!(
+            Parser::new(&table, "Ca2+").parse().unwrap(),
+            Substance::from(1, vec![Component::Element(Element::from("Ca", 1))], None)
+                .with_charge(2)
+        );
+
+        assert_eq!(
+            Parser::new(&table, "NO3-").parse().unwrap(),
+            Substance::from(
+                1,
+                vec![
+                    Component::Element(Element::from("N", 1)),
+                    Component::Element(Element::from("O", 3)),
+                ],
+                None,
+            )
+            .with_charge(-1)
+        );
+
+        assert_eq!(
+            Parser::new(&table, "PO4 3-").parse().unwrap(),
+            Substance::from(
+                1,
+                vec![
+                    Component::Element(Element::from("P", 1)),
+                    Component::Element(Element::from("O", 4)),
+                ],
+                None,
+            )
+            .with_charge(-3)
+        );
+
+        assert_eq!(
+            Parser::new(&table, "Hg2^2+").parse().unwrap(),
+            Substance::from(1, vec![Component::Element(Element::from("Hg", 2))], None)
+                .with_charge(2)
+        );
+
+        assert_eq!(
+            Parser::new(&table, "Fe+++").parse().unwrap(),
+            Substance::from(1, vec![Component::Element(Element::from("Fe", 1))], None)
+                .with_charge(3)
+        );
+    }
+
+    #[test]
+    fn parser_homoatomic_charge() {
+        let table = Table::new();
+
+        // subscript before single sign on single element is the charge
+        assert_eq!(
+            Parser::new(&table, "I3-").parse().unwrap(),
+            Substance::from(1, vec![Component::Element(Element::from("I", 1))], None)
+                .with_charge(-3)
+        );
+
+        assert_eq!(
+            Parser::new(&table, "O2-").parse().unwrap(),
+            Substance::from(1, vec![Component::Element(Element::from("O", 1))], None)
+                .with_charge(-2)
+        );
+
+        // caret or space keeps it a subscript
+        assert_eq!(
+            Parser::new(&table, "I3^-").parse().unwrap(),
+            Substance::from(1, vec![Component::Element(Element::from("I", 3))], None)
+                .with_charge(-1)
+        );
+
+        assert_eq!(
+            Parser::new(&table, "N3 -").parse().unwrap(),
+            Substance::from(1, vec![Component::Element(Element::from("N", 3))], None)
+                .with_charge(-1)
+        );
+
+        assert_eq!(
+            Parser::new(&table, "O2^-").parse().unwrap(),
+            Substance::from(1, vec![Component::Element(Element::from("O", 2))], None)
+                .with_charge(-1)
+        );
+    }
+
+    #[test]
+    fn parser_trailing_space() {
+        let table = Table::new();
+
+        assert!(matches!(
+            Parser::new(&table, "H2O ").parse(),
+            Err(Error::UnexpectedToken(token, 3)) if token == " "
+        ));
+    }
 }
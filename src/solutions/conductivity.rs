@@ -0,0 +1,150 @@
+use crate::solutions::ion_concentration::ionic_strength;
+use crate::solutions::IonConcentration;
+use crate::Ion;
+
+/// limiting molar conductivities of ions at 25 °C in S·cm²/mol
+const MOLAR_CONDUCTIVITIES: [(&str, f32); 37] = [
+    ("H+", 349.65),
+    ("Li+", 38.66),
+    ("Na+", 50.08),
+    ("K+", 73.48),
+    ("NH4+", 73.5),
+    ("Mg2+", 106.0),
+    ("Ca2+", 119.0),
+    ("Sr2+", 118.9),
+    ("Ba2+", 127.2),
+    ("Mn2+", 107.0),
+    ("Fe2+", 107.0),
+    ("Fe3+", 204.0),
+    ("Co2+", 110.0),
+    ("Ni2+", 100.0),
+    ("Cu2+", 107.2),
+    ("Zn2+", 105.6),
+    ("Al3+", 183.0),
+    ("OH-", 198.0),
+    ("F-", 55.4),
+    ("Cl-", 76.31),
+    ("Br-", 78.1),
+    ("I-", 76.8),
+    ("NO3-", 71.42),
+    ("NO2-", 71.8),
+    ("HCO3-", 44.5),
+    ("CO3 2-", 138.6),
+    ("SO4 2-", 160.0),
+    ("HSO4-", 52.0),
+    ("H2PO4-", 36.0),
+    ("HPO4 2-", 114.0),
+    ("PO4 3-", 207.0),
+    ("CH3COO-", 40.9),
+    ("HCOO-", 54.6),
+    ("C2O4 2-", 148.2),
+    ("ClO4-", 67.3),
+    ("MnO4-", 61.3),
+    ("MoO4 2-", 149.0),
+];
+
+/// An estimated electrical conductivity of solution at 25 °C
+///
+/// Sums limiting molar conductivities of ions, each reduced by square root
+/// of its activity coefficient by Güntelberg approximation
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conductivity {
+    contributions: Vec<(Ion, f32)>,
+    unknown: Vec<Ion>,
+}
+
+impl Conductivity {
+    pub(crate) fn new(ions: &[IonConcentration]) -> Self {
+        let root = ionic_strength(ions).sqrt();
+
+        let mut contributions = vec![];
+
+        let mut unknown = vec![];
+
+        ions.iter().for_each(|concentration| {
+            let ion = concentration.ion();
+
+            match molar_conductivity(ion) {
+                Some(limiting) => {
+                    let log_activity = -0.509 * ion.charge().pow(2) as f32 * root / (1. + root);
+
+                    let molar = limiting * 10_f32.powf(log_activity / 2.);
+
+                    // S·cm²/mol times mmol/L gives µS/cm, here in mS/cm
+                    contributions
+                        .push((ion.clone(), molar * concentration.mmol_per_litre() / 1000.));
+                }
+
+                None => unknown.push(ion.clone()),
+            }
+        });
+
+        Self {
+            contributions,
+            unknown,
+        }
+    }
+
+    /// get electrical conductivity in mS/cm, equal to dS/m
+    pub fn ec(&self) -> f32 {
+        self.contributions.iter().map(|(_, ec)| ec).sum()
+    }
+
+    /// list contributions of ions to conductivity in mS/cm
+    pub fn contributions(&self) -> &[(Ion, f32)] {
+        &self.contributions
+    }
+
+    /// list ions with unknown conductivity, left out of estimate
+    pub fn unknown(&self) -> &[Ion] {
+        &self.unknown
+    }
+}
+
+fn molar_conductivity(ion: &Ion) -> Option<f32> {
+    MOLAR_CONDUCTIVITIES
+        .iter()
+        .find(|(formula, _)| *formula == ion.formula())
+        .map(|(_, conductivity)| *conductivity)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+
+    #[test]
+    fn potassium_chloride_standards() {
+        let mut solution = Solution::new(1.).unwrap();
+
+        // 0.01 mol/L KCl standard is 1.413 mS/cm at 25 °C
+        solution.add("KCl", 0.7455).unwrap();
+
+        assert!((solution.conductivity().ec() - 1.413).abs() < 0.02);
+
+        let mut solution = Solution::new(1.).unwrap();
+
+        solution.add("KCl", 0.07455).unwrap();
+
+        assert!((solution.conductivity().ec() - 0.147).abs() < 0.002);
+    }
+
+    #[test]
+    fn contributions_of_ions() {
+        let mut solution = Solution::new(1.).unwrap();
+
+        solution.add("Ca(NO3)2*4H2O", 0.944).unwrap();
+        solution.add("C10H12N2O8FeNa", 0.05).unwrap();
+
+        let conductivity = solution.conductivity();
+
+        let nitrate = conductivity
+            .contributions()
+            .iter()
+            .find(|(ion, _)| ion.formula() == "NO3-")
+            .unwrap();
+
+        assert!(nitrate.1 > 0.5 && nitrate.1 < 0.571);
+
+        assert_eq!(conductivity.unknown().len(), 1);
+    }
+}
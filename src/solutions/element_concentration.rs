@@ -0,0 +1,50 @@
+use crate::chemistry::ChemicalElement;
+
+/// A concentration of element in solution
+///
+/// Contains mass, molar and equivalent concentrations of element.
+/// Equivalents are counted for elements carried by ions only
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElementConcentration {
+    chemical_element: ChemicalElement,
+    mg_per_litre: f32,
+    meq_per_litre: f32,
+}
+
+impl ElementConcentration {
+    pub(crate) fn new(chemical_element: ChemicalElement) -> Self {
+        Self {
+            chemical_element,
+            mg_per_litre: 0.0,
+            meq_per_litre: 0.0,
+        }
+    }
+
+    pub(crate) fn add_mass(&mut self, mg_per_litre: f32) {
+        self.mg_per_litre += mg_per_litre;
+    }
+
+    pub(crate) fn add_equivalents(&mut self, meq_per_litre: f32) {
+        self.meq_per_litre += meq_per_litre;
+    }
+
+    /// get chemical element
+    pub fn chemical_element(&self) -> ChemicalElement {
+        self.chemical_element
+    }
+
+    /// get mass concentration in mg/L, equal to ppm for dilute solutions
+    pub fn mg_per_litre(&self) -> f32 {
+        self.mg_per_litre
+    }
+
+    /// get molar concentration in mmol/L
+    pub fn mmol_per_litre(&self) -> f32 {
+        self.mg_per_litre / self.chemical_element.atomic_weight()
+    }
+
+    /// get equivalent concentration in meq/L
+    pub fn meq_per_litre(&self) -> f32 {
+        self.meq_per_litre
+    }
+}
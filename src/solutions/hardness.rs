@@ -0,0 +1,128 @@
+use crate::solutions::IonConcentration;
+use once_cell::sync::Lazy;
+
+/// equivalent weight of calcium carbonate hardness and alkalinity are expressed as
+static CALCIUM_CARBONATE: Lazy<f32> =
+    Lazy::new(|| crate::parse("CaCO3").unwrap().equivalent_weight());
+
+/// equivalent weight of bicarbonate alkalinity is commonly reported as
+static BICARBONATE: Lazy<f32> = Lazy::new(|| crate::parse("HCO3-").unwrap().equivalent_weight());
+
+/// A hardness of water
+///
+/// Contains equivalents of calcium and magnesium ions,
+/// reported in mg/L as CaCO3
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hardness {
+    calcium: f32,
+    magnesium: f32,
+}
+
+impl Hardness {
+    pub(crate) fn new(ions: &[IonConcentration]) -> Self {
+        Self {
+            calcium: equivalents(ions, &["Ca2+"]),
+            magnesium: equivalents(ions, &["Mg2+"]),
+        }
+    }
+
+    /// get calcium hardness in mg/L as CaCO3
+    pub fn calcium(&self) -> f32 {
+        self.calcium * *CALCIUM_CARBONATE
+    }
+
+    /// get magnesium hardness in mg/L as CaCO3
+    pub fn magnesium(&self) -> f32 {
+        self.magnesium * *CALCIUM_CARBONATE
+    }
+
+    /// get total hardness in mg/L as CaCO3
+    pub fn total(&self) -> f32 {
+        self.calcium() + self.magnesium()
+    }
+
+    /// get total hardness in meq/L
+    pub fn meq_per_litre(&self) -> f32 {
+        self.calcium + self.magnesium
+    }
+}
+
+/// An alkalinity of water
+///
+/// Counts equivalents of bicarbonate, carbonate and hydroxide
+/// less hydrogen ions
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Alkalinity {
+    meq_per_litre: f32,
+}
+
+impl Alkalinity {
+    pub(crate) fn new(ions: &[IonConcentration]) -> Self {
+        Self {
+            meq_per_litre: equivalents(ions, &["HCO3-", "CO3 2-", "OH-"])
+                - equivalents(ions, &["H+"]),
+        }
+    }
+
+    /// alkalinity from mg/L as CaCO3
+    pub fn from_calcium_carbonate(mg_per_litre: f32) -> Self {
+        Self {
+            meq_per_litre: mg_per_litre / *CALCIUM_CARBONATE,
+        }
+    }
+
+    /// alkalinity from mg/L of bicarbonate
+    pub fn from_bicarbonate(mg_per_litre: f32) -> Self {
+        Self {
+            meq_per_litre: mg_per_litre / *BICARBONATE,
+        }
+    }
+
+    /// get alkalinity in meq/L
+    pub fn meq_per_litre(&self) -> f32 {
+        self.meq_per_litre
+    }
+
+    /// get alkalinity in mg/L as CaCO3
+    pub fn as_calcium_carbonate(&self) -> f32 {
+        self.meq_per_litre * *CALCIUM_CARBONATE
+    }
+
+    /// get alkalinity in mg/L as HCO3
+    pub fn as_bicarbonate(&self) -> f32 {
+        self.meq_per_litre * *BICARBONATE
+    }
+}
+
+fn equivalents(ions: &[IonConcentration], formulas: &[&str]) -> f32 {
+    ions.iter()
+        .filter(|concentration| formulas.contains(&concentration.ion().formula()))
+        .map(|concentration| concentration.meq_per_litre())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Alkalinity;
+    use crate::WaterAnalysis;
+
+    #[test]
+    fn hardness_and_alkalinity() {
+        let water =
+            WaterAnalysis::from_key_values("Ca = 40.078\nMg = 12.153\nHCO3 = 122.03").unwrap();
+
+        let hardness = water.hardness();
+
+        assert!((hardness.calcium() - 100.09).abs() < 0.01);
+
+        assert!((hardness.magnesium() - 50.04).abs() < 0.01);
+
+        assert!((hardness.total() - 150.13).abs() < 0.02);
+
+        assert!((water.alkalinity().as_calcium_carbonate() - 100.09).abs() < 0.01);
+
+        let alkalinity = Alkalinity::from_calcium_carbonate(100.);
+
+        assert!((alkalinity.as_bicarbonate() - 121.93).abs() < 0.01);
+    }
+}
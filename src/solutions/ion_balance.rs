@@ -0,0 +1,66 @@
+use crate::solutions::IonConcentration;
+
+/// A balance of cation and anion equivalents
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IonBalance {
+    cations: f32,
+    anions: f32,
+}
+
+impl IonBalance {
+    pub(crate) fn new(ions: &[IonConcentration]) -> Self {
+        let sum = |sign: i32| -> f32 {
+            ions.iter()
+                .filter(|concentration| concentration.ion().charge().signum() == sign)
+                .map(|concentration| concentration.meq_per_litre())
+                .sum()
+        };
+
+        Self {
+            cations: sum(1),
+            anions: sum(-1),
+        }
+    }
+
+    /// get sum of cations in meq/L
+    pub fn cations(&self) -> f32 {
+        self.cations
+    }
+
+    /// get sum of anions in meq/L
+    pub fn anions(&self) -> f32 {
+        self.anions
+    }
+
+    /// get excess of cations over anions in meq/L
+    pub fn difference(&self) -> f32 {
+        self.cations - self.anions
+    }
+
+    /// get balance error in percent: difference to sum of cations and anions
+    pub fn error(&self) -> f32 {
+        match self.cations + self.anions {
+            total if total > 0.0 => self.difference() / total * 100.,
+            _ => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+
+    #[test]
+    fn balanced_salts() {
+        let mut solution = Solution::new(1.).unwrap();
+
+        solution.add("Ca(NO3)2*4H2O", 0.236).unwrap();
+        solution.add("K2SO4", 0.174).unwrap();
+
+        let balance = solution.ion_balance();
+
+        assert!((balance.cations() - 4.0).abs() < 0.01);
+
+        assert!(balance.error().abs() < 0.01);
+    }
+}
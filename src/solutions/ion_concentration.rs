@@ -0,0 +1,49 @@
+use crate::Ion;
+
+/// A concentration of ion in solution
+#[derive(Clone, Debug, PartialEq)]
+pub struct IonConcentration {
+    ion: Ion,
+    mmol_per_litre: f32,
+}
+
+impl IonConcentration {
+    pub(crate) fn new(ion: Ion) -> Self {
+        Self {
+            ion,
+            mmol_per_litre: 0.0,
+        }
+    }
+
+    pub(crate) fn add_moles(&mut self, mmol_per_litre: f32) {
+        self.mmol_per_litre += mmol_per_litre;
+    }
+
+    /// get ion
+    pub fn ion(&self) -> &Ion {
+        &self.ion
+    }
+
+    /// get molar concentration in mmol/L
+    pub fn mmol_per_litre(&self) -> f32 {
+        self.mmol_per_litre
+    }
+
+    /// get mass concentration in mg/L
+    pub fn mg_per_litre(&self) -> f32 {
+        self.mmol_per_litre * self.ion.compound().molar_mass()
+    }
+
+    /// get equivalent concentration in meq/L
+    pub fn meq_per_litre(&self) -> f32 {
+        self.mmol_per_litre * self.ion.charge().unsigned_abs() as f32
+    }
+}
+
+/// ionic strength in mol/L: half sum of molarities times squared charges
+pub(crate) fn ionic_strength(ions: &[IonConcentration]) -> f32 {
+    ions.iter()
+        .map(|ion| ion.mmol_per_litre() / 1000. * ion.ion().charge().pow(2) as f32)
+        .sum::<f32>()
+        / 2.
+}
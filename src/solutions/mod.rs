@@ -0,0 +1,21 @@
+mod conductivity;
+mod element_concentration;
+mod hardness;
+mod ion_balance;
+mod ion_concentration;
+mod recipe_solver;
+mod solubility;
+mod solution;
+mod stock_planner;
+mod water_analysis;
+
+pub use conductivity::Conductivity;
+pub use element_concentration::ElementConcentration;
+pub use hardness::{Alkalinity, Hardness};
+pub use ion_balance::IonBalance;
+pub use ion_concentration::IonConcentration;
+pub use recipe_solver::{Recipe, RecipeSolver};
+pub use solubility::{saturation, solubility, Saturation};
+pub use solution::Solution;
+pub use stock_planner::{SolubilityWarning, StockPlan, StockPlanner, StockTank};
+pub use water_analysis::WaterAnalysis;
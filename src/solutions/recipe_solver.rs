@@ -0,0 +1,404 @@
+use crate::solutions::{Solution, WaterAnalysis};
+use crate::{Error, Fertiliser, Product, PERIODIC_TABLE};
+use std::collections::HashMap;
+
+const MAX_SWEEPS: usize = 20000;
+
+const TOLERANCE: f64 = 1e-12;
+
+/// A target concentration of element
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Target {
+    mg_per_litre: f32,
+    weight: f32,
+}
+
+/// An inverse recipe solver
+///
+/// Finds grams of available salts which best match target concentrations
+/// of elements: non-negative least squares of relative deviations with
+/// per-element weights, plus optional cost of salts. Elements of source
+/// water are subtracted from targets
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecipeSolver {
+    volume: f32,
+    targets: HashMap<&'static str, Target>,
+    salts: Vec<(Fertiliser, f32)>,
+    cost_weight: f32,
+    water: WaterAnalysis,
+}
+
+impl RecipeSolver {
+    /// takes positive volume of solution in litres
+    pub fn new(volume: f32) -> Result<Self, Error> {
+        if volume <= 0.0 || !volume.is_finite() {
+            return Err(Error::OutOfRange("volume".to_string(), volume));
+        }
+
+        Ok(Self {
+            volume,
+            targets: HashMap::new(),
+            salts: vec![],
+            cost_weight: 1.0,
+            water: WaterAnalysis::new(),
+        })
+    }
+
+    /// set target concentration of element in mg/L (ppm)
+    pub fn target(&mut self, symbol: &str, mg_per_litre: f32) -> Result<(), Error> {
+        self.weighted_target(symbol, mg_per_litre, 1.0)
+    }
+
+    /// set target concentration of element in mg/L with weight of its deviation
+    pub fn weighted_target(
+        &mut self,
+        symbol: &str,
+        mg_per_litre: f32,
+        weight: f32,
+    ) -> Result<(), Error> {
+        let chemical_element = PERIODIC_TABLE
+            .lookup(symbol)
+            .ok_or(Error::UnknownSymbol(symbol.to_string()))?;
+
+        self.targets.insert(
+            chemical_element.symbol(),
+            Target {
+                mg_per_litre,
+                weight,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// add available salt defined by formula
+    pub fn salt(&mut self, formula: &str) -> Result<(), Error> {
+        self.priced_salt(formula, 0.0)
+    }
+
+    /// add available salt defined by formula with price per kilogram
+    pub fn priced_salt(&mut self, formula: &str, price: f32) -> Result<(), Error> {
+        self.salts
+            .push((Fertiliser::Compound(crate::parse(formula)?), price));
+
+        Ok(())
+    }
+
+    /// add available product defined by label
+    pub fn product(&mut self, product: Product) {
+        self.priced_product(product, 0.0);
+    }
+
+    /// add available product defined by label with price per kilogram
+    pub fn priced_product(&mut self, product: Product, price: f32) {
+        self.salts.push((Fertiliser::Product(product), price));
+    }
+
+    /// set weight of cost of salts per litre against squared relative deviations
+    pub fn set_cost_weight(&mut self, cost_weight: f32) {
+        self.cost_weight = cost_weight;
+    }
+
+    /// set analysis of source water
+    pub fn set_water(&mut self, water: WaterAnalysis) {
+        self.water = water;
+    }
+
+    /// find grams of salts for volume of solution, with their cost, deviations of
+    /// achieved concentrations and elements no salt can supply
+    pub fn solve(&self) -> Recipe {
+        let water = self.water.elements();
+        let targets: Vec<(&'static str, Target)> = self
+            .targets
+            .iter()
+            .map(|(symbol, target)| (*symbol, *target))
+            .collect();
+
+        // mg/L of each targeted element per g/L of each salt, scaled by target
+        let matrix: Vec<Vec<f64>> = targets
+            .iter()
+            .map(|(symbol, target)| {
+                let scale = (target.mg_per_litre as f64).max(1.0);
+
+                self.salts
+                    .iter()
+                    .map(|(fertiliser, _)| fertiliser.element_percent(symbol) as f64 * 10. / scale)
+                    .collect()
+            })
+            .collect();
+
+        let goals: Vec<f64> = targets
+            .iter()
+            .map(|(symbol, target)| {
+                let missing = target.mg_per_litre - water.get(symbol).copied().unwrap_or(0.0);
+
+                missing.max(0.0) as f64 / (target.mg_per_litre as f64).max(1.0)
+            })
+            .collect();
+
+        let weights: Vec<f64> = targets
+            .iter()
+            .map(|(_, target)| target.weight as f64)
+            .collect();
+
+        let costs: Vec<f64> = self
+            .salts
+            .iter()
+            .map(|(_, price)| *price as f64 / 1000. * self.cost_weight as f64)
+            .collect();
+
+        let grams_per_litre = minimize(&matrix, &goals, &weights, &costs);
+
+        let masses: Vec<(Fertiliser, f32)> = self
+            .salts
+            .iter()
+            .zip(&grams_per_litre)
+            .map(|((fertiliser, _), x)| (fertiliser.clone(), *x as f32 * self.volume))
+            .collect();
+
+        let cost = self
+            .salts
+            .iter()
+            .zip(&masses)
+            .map(|((_, price), (_, mass))| price * mass / 1000.)
+            .sum();
+
+        // volume is checked by constructor
+        let mut solution = Solution::new(self.volume).unwrap();
+
+        solution.set_water(self.water.clone());
+
+        masses
+            .iter()
+            .filter(|(_, mass)| *mass > 0.0)
+            .for_each(|(fertiliser, mass)| solution.add_fertiliser(fertiliser.clone(), *mass));
+
+        let concentrations = solution.concentrations();
+
+        let deviations = targets
+            .iter()
+            .map(|(symbol, target)| {
+                let achieved = concentrations
+                    .get(symbol)
+                    .map(|concentration| concentration.mg_per_litre())
+                    .unwrap_or(0.0);
+
+                (*symbol, achieved - target.mg_per_litre)
+            })
+            .collect();
+
+        let mut unreachable: Vec<&'static str> = targets
+            .iter()
+            .filter(|(symbol, target)| {
+                water.get(symbol).copied().unwrap_or(0.0) < target.mg_per_litre
+                    && !self
+                        .salts
+                        .iter()
+                        .any(|(fertiliser, _)| fertiliser.element_percent(symbol) > 0.0)
+            })
+            .map(|(symbol, _)| *symbol)
+            .collect();
+
+        unreachable.sort();
+
+        Recipe {
+            masses,
+            solution,
+            deviations,
+            unreachable,
+            cost,
+        }
+    }
+}
+
+/// projected coordinate descent for
+/// sum of w * (A x - b)^2 + sum of c * x, where x >= 0
+fn minimize(matrix: &[Vec<f64>], goals: &[f64], weights: &[f64], costs: &[f64]) -> Vec<f64> {
+    let mut x = vec![0.0; costs.len()];
+
+    let mut residuals: Vec<f64> = goals.iter().map(|goal| -goal).collect();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut max_change: f64 = 0.0;
+
+        for j in 0..x.len() {
+            let curvature: f64 = (0..goals.len())
+                .map(|i| 2. * weights[i] * matrix[i][j] * matrix[i][j])
+                .sum();
+
+            if curvature == 0.0 {
+                continue;
+            }
+
+            let gradient: f64 = (0..goals.len())
+                .map(|i| 2. * weights[i] * matrix[i][j] * residuals[i])
+                .sum::<f64>()
+                + costs[j];
+
+            let value = (x[j] - gradient / curvature).max(0.0);
+
+            let change = value - x[j];
+
+            if change != 0.0 {
+                (0..goals.len()).for_each(|i| residuals[i] += matrix[i][j] * change);
+
+                x[j] = value;
+            }
+
+            max_change = max_change.max(change.abs());
+        }
+
+        if max_change < TOLERANCE {
+            break;
+        }
+    }
+
+    x
+}
+
+/// A recipe found by solver
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recipe {
+    masses: Vec<(Fertiliser, f32)>,
+    solution: Solution,
+    deviations: HashMap<&'static str, f32>,
+    unreachable: Vec<&'static str>,
+    cost: f32,
+}
+
+impl Recipe {
+    /// list salts with grams to dissolve, in order they were added
+    pub fn masses(&self) -> &[(Fertiliser, f32)] {
+        &self.masses
+    }
+
+    /// get solution prepared by recipe
+    pub fn solution(&self) -> &Solution {
+        &self.solution
+    }
+
+    /// list deviations of achieved concentrations from targets in mg/L
+    pub fn deviations(&self) -> &HashMap<&'static str, f32> {
+        &self.deviations
+    }
+
+    /// list targeted elements none of salts contains and water lacks
+    pub fn unreachable(&self) -> &[&'static str] {
+        &self.unreachable
+    }
+
+    /// get total cost of salts
+    pub fn cost(&self) -> f32 {
+        self.cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecipeSolver;
+    use crate::Error;
+
+    #[test]
+    fn exact_recipe() {
+        let mut solver = RecipeSolver::new(100.).unwrap();
+
+        solver.target("N", 150.).unwrap();
+        solver.target("K", 200.).unwrap();
+        solver.target("Ca", 160.).unwrap();
+
+        solver.salt("KNO3").unwrap();
+        solver.salt("Ca(NO3)2*4H2O").unwrap();
+        solver.salt("K2SO4").unwrap();
+
+        let recipe = solver.solve();
+
+        recipe
+            .deviations()
+            .values()
+            .for_each(|deviation| assert!(deviation.abs() < 0.1));
+
+        assert!((recipe.masses()[1].1 - 94.28).abs() < 0.1);
+
+        assert!(recipe.unreachable().is_empty());
+    }
+
+    #[test]
+    fn unreachable_targets() {
+        let mut solver = RecipeSolver::new(1.).unwrap();
+
+        solver.target("K", 200.).unwrap();
+        solver.target("Fe", 2.).unwrap();
+
+        solver.salt("KNO3").unwrap();
+
+        let recipe = solver.solve();
+
+        assert_eq!(recipe.unreachable(), &["Fe"]);
+
+        assert!((recipe.deviations()["Fe"] + 2.).abs() < 0.001);
+
+        assert!(recipe.deviations()["K"].abs() < 0.1);
+
+        assert!(matches!(
+            solver.target("Xx", 1.),
+            Err(Error::UnknownSymbol(symbol)) if symbol == "Xx"
+        ));
+    }
+
+    #[test]
+    fn cheaper_salt_preferred() {
+        let mut solver = RecipeSolver::new(1.).unwrap();
+
+        solver.target("K", 100.).unwrap();
+
+        solver.priced_salt("KCl", 1.).unwrap();
+        solver.priced_salt("KBr", 5.).unwrap();
+
+        let recipe = solver.solve();
+
+        assert!(recipe.masses()[1].1 < 0.001);
+
+        assert!(recipe.deviations()["K"].abs() < 1.);
+    }
+
+    #[test]
+    fn source_water_subtracted() {
+        let mut solver = RecipeSolver::new(1.).unwrap();
+
+        solver.target("Ca", 160.).unwrap();
+        solver.target("Mg", 10.).unwrap();
+
+        solver.set_water(crate::WaterAnalysis::from_key_values("Ca = 40\nMg = 12").unwrap());
+
+        solver.salt("Ca(NO3)2").unwrap();
+
+        let recipe = solver.solve();
+
+        // 120 mg/L of calcium is left to add
+        assert!((recipe.masses()[0].1 - 0.4913).abs() < 0.001);
+
+        assert!(recipe.deviations()["Ca"].abs() < 0.1);
+
+        assert!((recipe.deviations()["Mg"] - 2.).abs() < 0.01);
+
+        assert!(recipe.unreachable().is_empty());
+    }
+
+    #[test]
+    fn products_with_salts() {
+        let mut solver = RecipeSolver::new(1.).unwrap();
+
+        solver.target("N", 100.).unwrap();
+        solver.target("K", 100.).unwrap();
+
+        solver.product(crate::Product::from_label("13-0-46").unwrap());
+        solver.salt("NH4NO3").unwrap();
+
+        let recipe = solver.solve();
+
+        recipe
+            .deviations()
+            .values()
+            .for_each(|deviation| assert!(deviation.abs() < 0.1));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{Compound, Error};
+use once_cell::sync::Lazy;
+
+/// temperatures of solubility table in °C
+const TEMPERATURES: [f32; 8] = [0., 10., 20., 30., 40., 60., 80., 100.];
+
+/// solubility of anhydrous salts in grams per 100 g of water at
+/// temperatures of table, shorter rows end where data ends
+const SOLUBILITIES: [(&str, &[f32]); 28] = [
+    ("KNO3", &[13.9, 21.2, 31.6, 45.3, 61.3, 106., 167., 245.]),
+    ("Ca(NO3)2", &[102., 115., 129., 152., 191., 358., 363.]),
+    ("NH4NO3", &[118., 150., 192., 242., 297., 421., 580., 1024.]),
+    ("NaNO3", &[73., 80.8, 87.6, 94.9, 102., 122., 148., 180.]),
+    ("Mg(NO3)2", &[62.1, 66., 69.5, 73.6, 78.9]),
+    ("KH2PO4", &[14.8, 18.3, 22.6, 28., 35.5, 50.2, 70.4, 83.5]),
+    (
+        "NH4H2PO4",
+        &[22.7, 29.5, 37.4, 46.4, 56.7, 82.5, 118., 173.],
+    ),
+    ("(NH4)2HPO4", &[42.9, 62.9, 68.9, 75.1, 81.8, 97.2]),
+    ("K2SO4", &[7.4, 9.3, 11.1, 13., 14.8, 18.2, 21.4, 24.1]),
+    ("MgSO4", &[22., 28.2, 33.7, 38.9, 44.5, 54.6, 55.8, 50.4]),
+    ("(NH4)2SO4", &[70.6, 73., 75.4, 78., 81., 88., 95.3, 103.]),
+    ("Na2SO4", &[4.9, 9.1, 19.5, 40.8, 48.8, 45.3, 43.7, 42.5]),
+    (
+        "CaSO4",
+        &[0.223, 0.244, 0.255, 0.264, 0.265, 0.244, 0.234, 0.205],
+    ),
+    ("FeSO4", &[28.8, 40., 48., 60., 73.3, 100.7, 79.9, 57.8]),
+    ("MnSO4", &[52.9, 59.7, 62.9, 62.9, 60., 53.6, 45.6, 35.3]),
+    ("ZnSO4", &[41.6, 47.2, 53.8, 61.3, 70.5, 75.4, 71.1, 60.5]),
+    ("CuSO4", &[14.3, 17.4, 20.7, 25., 28.5, 40., 55., 75.4]),
+    ("KCl", &[28., 31.2, 34.2, 37.2, 40.1, 45.8, 51.3, 56.3]),
+    ("NaCl", &[35.7, 35.8, 35.9, 36.1, 36.4, 37.1, 38., 39.2]),
+    ("CaCl2", &[59.5, 64.7, 74.5, 100., 128., 137., 147., 159.]),
+    ("MgCl2", &[52.9, 53.6, 54.6, 55.8, 57.5, 61., 66.1, 73.3]),
+    ("H3BO3", &[2.67, 3.72, 5.04, 6.6, 8.72, 14.8, 23.6, 40.2]),
+    ("Na2MoO4", &[44.1, 64.7, 65.3, 66.9, 68.6, 71.8]),
+    ("Na2B4O7", &[1.11, 1.6, 2.56, 3.86, 6.67, 19., 31.4, 52.5]),
+    ("K2CO3", &[105., 108., 111., 114., 117., 127., 140., 156.]),
+    ("KHCO3", &[22.5, 27.4, 33.7, 39.9, 47.5, 65.6]),
+    ("KOH", &[95.7, 103., 112., 126., 134., 154.]),
+    ("CO(NH2)2", &[66.7, 85., 108., 133., 167., 251., 400., 733.]),
+];
+
+/// sorted atoms counts of anhydrous form
+type Composition = Vec<(&'static str, usize)>;
+
+/// formulas of table normalised to composition
+static TABLE: Lazy<Vec<(Composition, &'static [f32])>> = Lazy::new(|| {
+    SOLUBILITIES
+        .iter()
+        .map(|(formula, values)| (normalise(&crate::parse(*formula).unwrap()), *values))
+        .collect()
+});
+
+/// A saturation of salt dissolved in water
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Saturation {
+    concentration: f32,
+    solubility: f32,
+}
+
+impl Saturation {
+    /// get dissolved anhydrous salt in grams per 100 g of water
+    pub fn concentration(&self) -> f32 {
+        self.concentration
+    }
+
+    /// get solubility of anhydrous salt in grams per 100 g of water
+    pub fn solubility(&self) -> f32 {
+        self.solubility
+    }
+
+    /// get ratio of concentration to solubility
+    pub fn ratio(&self) -> f32 {
+        self.concentration / self.solubility
+    }
+
+    /// check if salt exceeds its solubility
+    pub fn is_saturated(&self) -> bool {
+        self.concentration > self.solubility
+    }
+}
+
+/// get solubility of salt in grams of anhydrous salt per 100 g of water
+/// at temperature in °C, interpolated linearly and clamped to table range
+pub fn solubility(formula: &str, temperature: f32) -> Result<f32, Error> {
+    compound_solubility(&crate::parse(formula)?, temperature)
+        .ok_or(Error::UnknownSolubility(formula.to_string()))
+}
+
+/// check mass in grams of salt, in any hydrate form, dissolved
+/// in volume of water in litres at temperature in °C
+pub fn saturation(
+    formula: &str,
+    mass: f32,
+    volume: f32,
+    temperature: f32,
+) -> Result<Saturation, Error> {
+    compound_saturation(&crate::parse(formula)?, mass, volume, temperature)
+        .ok_or(Error::UnknownSolubility(formula.to_string()))
+}
+
+pub(crate) fn compound_solubility(compound: &Compound, temperature: f32) -> Option<f32> {
+    let composition = normalise(compound);
+
+    TABLE
+        .iter()
+        .find(|(key, _)| *key == composition)
+        .map(|(_, values)| interpolate(values, temperature))
+}
+
+/// takes mass of reagent, impurities are left out and water
+/// of crystallisation joins solvent when hydrate dissolves
+pub(crate) fn compound_saturation(
+    compound: &Compound,
+    mass: f32,
+    volume: f32,
+    temperature: f32,
+) -> Option<Saturation> {
+    let solubility = compound_solubility(compound, temperature)?;
+
+    let water = mass * compound.hydrate_percent().unwrap_or(0.0) / 100.;
+
+    let salt_mass = compound.pure_mass(mass) - water;
+
+    let water_mass = volume * 1000. + water;
+
+    Some(Saturation {
+        concentration: salt_mass / water_mass * 100.,
+        solubility,
+    })
+}
+
+fn interpolate(values: &[f32], temperature: f32) -> f32 {
+    let last = values.len() - 1;
+
+    match TEMPERATURES[..=last]
+        .iter()
+        .position(|point| *point >= temperature)
+    {
+        Some(0) => values[0],
+        None => values[last],
+        Some(index) => {
+            let (t0, t1) = (TEMPERATURES[index - 1], TEMPERATURES[index]);
+
+            values[index - 1] + (values[index] - values[index - 1]) * (temperature - t0) / (t1 - t0)
+        }
+    }
+}
+
+fn normalise(compound: &Compound) -> Composition {
+    let anhydrous = compound.anhydrous();
+
+    let mut composition: Composition = anhydrous
+        .components()
+        .iter()
+        .map(|(symbol, component)| (*symbol, component.atoms_count()))
+        .collect();
+
+    composition.sort();
+
+    composition
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{saturation, solubility};
+
+    #[test]
+    fn interpolated_solubility() {
+        assert_eq!(solubility("KNO3", 20.).unwrap(), 31.6);
+
+        assert!((solubility("KNO3", 25.).unwrap() - 38.45).abs() < 0.01);
+
+        assert_eq!(solubility("KNO3", 120.).unwrap(), 245.);
+
+        assert_eq!(solubility("N2H4O3", 20.).unwrap(), 192.);
+
+        assert!(solubility("C6H12O6", 20.).is_err());
+
+        // formula coefficient doesn't change solubility
+        assert_eq!(solubility("2KNO3", 20.).unwrap(), 31.6);
+
+        assert_eq!(solubility("2MgSO4*7H2O", 20.).unwrap(), 33.7);
+    }
+
+    #[test]
+    fn saturation_of_hydrates() {
+        assert!(saturation("KNO3", 500., 1., 20.).unwrap().is_saturated());
+
+        assert!(!saturation("KNO3", 500., 1., 60.).unwrap().is_saturated());
+
+        let hydrate = saturation("MgSO4*7H2O", 246.47, 1., 20.).unwrap();
+
+        // 120.37 g of MgSO4 in 1126.1 g of water
+        assert!((hydrate.concentration() - 10.69).abs() < 0.01);
+
+        let doubled = saturation("2MgSO4*7H2O", 246.47, 1., 20.).unwrap();
+
+        assert!((doubled.concentration() - 10.69).abs() < 0.01);
+    }
+}
@@ -0,0 +1,466 @@
+use crate::solutions::ion_concentration::ionic_strength;
+use crate::solutions::{
+    Conductivity, ElementConcentration, IonBalance, IonConcentration, WaterAnalysis,
+};
+use crate::{
+    Compound, Error, Fertiliser, Ion, NitrogenForm, Product, GAS_CONSTANT, PERIODIC_TABLE,
+};
+use std::collections::HashMap;
+
+/// A solution of fertilisers dissolved in water
+///
+/// Takes masses of compounds or label-defined products in grams
+/// and volume of solution in litres, optionally on top of source water
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solution {
+    volume: f32,
+    doses: Vec<(Fertiliser, f32)>,
+    water: WaterAnalysis,
+}
+
+impl Solution {
+    /// takes positive volume of solution in litres
+    pub fn new(volume: f32) -> Result<Self, Error> {
+        if volume <= 0.0 || !volume.is_finite() {
+            return Err(Error::OutOfRange("volume".to_string(), volume));
+        }
+
+        Ok(Self {
+            volume,
+            doses: vec![],
+            water: WaterAnalysis::new(),
+        })
+    }
+
+    /// set analysis of source water fertilisers are dissolved in
+    pub fn set_water(&mut self, water: WaterAnalysis) {
+        self.water = water;
+    }
+
+    /// get analysis of source water
+    pub fn water(&self) -> &WaterAnalysis {
+        &self.water
+    }
+
+    /// add mass in grams of compound defined by formula
+    pub fn add(&mut self, formula: &str, mass: f32) -> Result<(), Error> {
+        self.add_compound(crate::parse(formula)?, mass);
+
+        Ok(())
+    }
+
+    /// add mass in grams of parsed compound
+    pub fn add_compound(&mut self, compound: Compound, mass: f32) {
+        self.add_fertiliser(Fertiliser::Compound(compound), mass);
+    }
+
+    /// add mass in grams of product defined by label
+    pub fn add_product(&mut self, product: Product, mass: f32) {
+        self.add_fertiliser(Fertiliser::Product(product), mass);
+    }
+
+    /// add mass in grams of fertiliser
+    pub fn add_fertiliser(&mut self, fertiliser: Fertiliser, mass: f32) {
+        self.doses.push((fertiliser, mass));
+    }
+
+    /// get volume of solution in litres
+    pub fn volume(&self) -> f32 {
+        self.volume
+    }
+
+    /// list fertilisers with their masses in grams
+    pub fn doses(&self) -> &[(Fertiliser, f32)] {
+        &self.doses
+    }
+
+    /// list concentrations of elements
+    pub fn concentrations(&self) -> HashMap<&'static str, ElementConcentration> {
+        let mut concentrations: HashMap<&'static str, ElementConcentration> = HashMap::new();
+
+        self.doses.iter().for_each(|(fertiliser, mass)| {
+            let mg_per_litre = mass * 1000. / self.volume;
+
+            fertiliser
+                .element_percents()
+                .into_iter()
+                .for_each(|(symbol, percent)| {
+                    concentrations
+                        .entry(symbol)
+                        .or_insert_with(|| {
+                            ElementConcentration::new(PERIODIC_TABLE.lookup(symbol).unwrap())
+                        })
+                        .add_mass(mg_per_litre * percent / 100.);
+                });
+        });
+
+        // equivalents are known for ions of compounds only
+        self.compounds()
+            .into_iter()
+            .for_each(|(compound, mg_per_litre)| {
+                if let Ok(dissociation) = compound.dissociate() {
+                    let mmol_per_litre = mg_per_litre / compound.molar_mass();
+
+                    dissociation.ions().iter().for_each(|ion| {
+                        let symbol = match characteristic_element(ion.compound()) {
+                            Some(symbol) => symbol,
+                            None => return,
+                        };
+
+                        let meq_per_litre = mmol_per_litre
+                            * ion.count() as f32
+                            * ion.charge().unsigned_abs() as f32;
+
+                        if let Some(concentration) = concentrations.get_mut(symbol) {
+                            concentration.add_equivalents(meq_per_litre);
+                        }
+                    });
+                }
+            });
+
+        self.water.species().iter().for_each(|(ion, mg_per_litre)| {
+            let compound = ion.compound();
+
+            compound
+                .components()
+                .iter()
+                .for_each(|(symbol, component)| {
+                    concentrations
+                        .entry(symbol)
+                        .or_insert_with(|| ElementConcentration::new(component.chemical_element()))
+                        .add_mass(mg_per_litre * component.mass_percent() / 100.);
+                });
+
+            if let Some(symbol) = characteristic_element(compound) {
+                concentrations.get_mut(symbol).unwrap().add_equivalents(
+                    mg_per_litre / compound.molar_mass() * ion.charge().unsigned_abs() as f32,
+                );
+            }
+        });
+
+        concentrations
+    }
+
+    /// list concentrations of ions of dissociated compounds and source water,
+    /// compounds which can't be dissociated are left out
+    pub fn ions(&self) -> Vec<IonConcentration> {
+        let mut ions: Vec<IonConcentration> = vec![];
+
+        self.compounds()
+            .into_iter()
+            .for_each(|(compound, mg_per_litre)| {
+                let dissociation = match compound.dissociate() {
+                    Ok(dissociation) => dissociation,
+                    Err(_) => return,
+                };
+
+                let mmol_per_litre = mg_per_litre / compound.molar_mass();
+
+                dissociation.ions().iter().for_each(|ion| {
+                    add_ion(&mut ions, ion, mmol_per_litre * ion.count() as f32);
+                });
+            });
+
+        self.water.ions().iter().for_each(|concentration| {
+            add_ion(
+                &mut ions,
+                concentration.ion(),
+                concentration.mmol_per_litre(),
+            );
+        });
+
+        ions
+    }
+
+    /// estimate electrical conductivity at 25 °C from ions
+    pub fn conductivity(&self) -> Conductivity {
+        Conductivity::new(&self.ions())
+    }
+
+    /// get balance of cations and anions
+    pub fn ion_balance(&self) -> IonBalance {
+        IonBalance::new(&self.ions())
+    }
+
+    /// get ionic strength in mol/L
+    pub fn ionic_strength(&self) -> f32 {
+        ionic_strength(&self.ions())
+    }
+
+    /// get osmolarity in mOsm/L: ions plus molecules of compounds and
+    /// neutral species of water, products are left out
+    pub fn osmolarity(&self) -> f32 {
+        let molecules: f32 = self
+            .compounds()
+            .into_iter()
+            .filter(|(compound, _)| compound.dissociate().is_err())
+            .map(|(compound, mg_per_litre)| mg_per_litre / compound.molar_mass())
+            .chain(
+                self.water
+                    .species()
+                    .iter()
+                    .filter(|(ion, _)| ion.charge() == 0)
+                    .map(|(ion, mg_per_litre)| mg_per_litre / ion.compound().molar_mass()),
+            )
+            .sum();
+
+        let ions: f32 = self
+            .ions()
+            .iter()
+            .map(|concentration| concentration.mmol_per_litre())
+            .sum();
+
+        molecules + ions
+    }
+
+    /// get ideal osmotic pressure in kPa at temperature in °C
+    pub fn osmotic_pressure(&self, temperature: f32) -> f32 {
+        // mOsm/L equals mol/m³, so pressure comes in Pa
+        self.osmolarity() * GAS_CONSTANT * (temperature + 273.15) / 1000.
+    }
+
+    /// list concentrations of nitrogen in mg/L by form of nitrogen,
+    /// nitrogen of label-defined products is counted as other form
+    pub fn nitrogen_forms(&self) -> HashMap<NitrogenForm, f32> {
+        let mut forms = HashMap::new();
+
+        self.doses.iter().for_each(|(fertiliser, mass)| {
+            let mg_per_litre = mass * 1000. / self.volume;
+
+            match fertiliser {
+                Fertiliser::Compound(compound) => {
+                    compound
+                        .nitrogen_forms()
+                        .into_iter()
+                        .for_each(|(form, mass_percent)| {
+                            *forms.entry(form).or_insert(0.0) += mg_per_litre * mass_percent / 100.;
+                        });
+                }
+
+                Fertiliser::Product(product) => {
+                    *forms.entry(NitrogenForm::Other).or_insert(0.0) +=
+                        mg_per_litre * product.analysis().element_percent("N") / 100.;
+                }
+            }
+        });
+
+        self.water.species().iter().for_each(|(ion, mg_per_litre)| {
+            ion.compound()
+                .nitrogen_forms()
+                .into_iter()
+                .for_each(|(form, mass_percent)| {
+                    *forms.entry(form).or_insert(0.0) += mg_per_litre * mass_percent / 100.;
+                });
+        });
+
+        forms
+    }
+
+    /// list compounds of doses and their impurities with mg/L of reagent
+    /// their molar masses and mass percents apply to
+    fn compounds(&self) -> Vec<(&Compound, f32)> {
+        self.doses
+            .iter()
+            .filter_map(|(fertiliser, mass)| fertiliser.compound().map(|compound| (compound, mass)))
+            .flat_map(|(compound, mass)| {
+                compound
+                    .parts()
+                    .into_iter()
+                    .map(move |(part, percent)| (part, mass * 1000. / self.volume * percent / 100.))
+            })
+            .collect()
+    }
+}
+
+fn add_ion(ions: &mut Vec<IonConcentration>, ion: &Ion, mmol_per_litre: f32) {
+    let position = ions
+        .iter()
+        .position(|concentration| concentration.ion().formula() == ion.formula())
+        .unwrap_or_else(|| {
+            ions.push(IonConcentration::new(ion.clone().with_count(1)));
+
+            ions.len() - 1
+        });
+
+    ions[position].add_moles(mmol_per_litre);
+}
+
+/// an element ion charge is attributed to: the only element
+/// besides oxygen and hydrogen, or the element of monatomic ion
+pub(crate) fn characteristic_element(ion: &Compound) -> Option<&'static str> {
+    let mut symbols: Vec<&'static str> = ion.components().keys().copied().collect();
+
+    if symbols.len() > 1 {
+        symbols.retain(|symbol| *symbol != "O" && *symbol != "H");
+    }
+
+    match symbols.as_slice() {
+        [symbol] => Some(symbol),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::{NitrogenForm, Product, WaterAnalysis};
+
+    #[test]
+    fn element_concentrations() {
+        let mut solution = Solution::new(10.).unwrap();
+
+        solution.add("Ca(NO3)2*4H2O", 9.44).unwrap();
+
+        solution.add("KNO3", 5.05).unwrap();
+
+        solution.add("MgSO4*7H2O", 4.93).unwrap();
+
+        let concentrations = solution.concentrations();
+
+        let calcium = &concentrations["Ca"];
+
+        assert!((calcium.mg_per_litre() - 160.2).abs() < 0.1);
+
+        assert!((calcium.mmol_per_litre() - 4.0).abs() < 0.01);
+
+        assert!((calcium.meq_per_litre() - 8.0).abs() < 0.01);
+
+        let nitrogen = &concentrations["N"];
+
+        assert!((nitrogen.mmol_per_litre() - 13.0).abs() < 0.02);
+
+        assert!((nitrogen.meq_per_litre() - 13.0).abs() < 0.02);
+
+        let sulfur = &concentrations["S"];
+
+        assert!((sulfur.meq_per_litre() - 4.0).abs() < 0.01);
+
+        assert_eq!(concentrations["H"].meq_per_litre(), 0.);
+    }
+
+    #[test]
+    fn nitrogen_forms() {
+        let mut solution = Solution::new(1.).unwrap();
+
+        solution.add("NH4NO3", 0.08).unwrap();
+
+        solution.add("KNO3", 0.101).unwrap();
+
+        let forms = solution.nitrogen_forms();
+
+        assert!((forms[&NitrogenForm::Nitrate] - 28.0).abs() < 0.1);
+
+        assert!((forms[&NitrogenForm::Ammonium] - 14.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn osmotic_properties() {
+        let mut solution = Solution::new(1.).unwrap();
+
+        solution.add("NaCl", 5.844).unwrap();
+        solution.add("CaCl2", 11.098).unwrap();
+        solution.add("C6H12O6", 18.016).unwrap();
+
+        assert!((solution.ionic_strength() - 0.4).abs() < 0.001);
+
+        assert!((solution.osmolarity() - 600.).abs() < 0.1);
+
+        assert!((solution.osmotic_pressure(25.) - 1487.3).abs() < 0.5);
+    }
+
+    #[test]
+    fn source_water() {
+        let mut solution = Solution::new(1.).unwrap();
+
+        solution.set_water(WaterAnalysis::from_key_values("Ca = 40\nNO3 = 62\nSiO2 = 60").unwrap());
+
+        solution.add("Ca(NO3)2", 0.16409).unwrap();
+
+        let concentrations = solution.concentrations();
+
+        assert!((concentrations["Ca"].mg_per_litre() - 80.08).abs() < 0.1);
+
+        assert!((concentrations["Ca"].meq_per_litre() - 4.0).abs() < 0.01);
+
+        assert!((solution.nitrogen_forms()[&NitrogenForm::Nitrate] - 42.0).abs() < 0.1);
+
+        assert!((solution.osmolarity() - 6.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn products_with_compounds() {
+        let mut solution = Solution::new(1.).unwrap();
+
+        solution.add_product(
+            Product::from_label("Calcinit 15.5-0-0 + 19% Ca").unwrap(),
+            1.,
+        );
+
+        solution.add("KNO3", 0.101).unwrap();
+
+        let concentrations = solution.concentrations();
+
+        assert!((concentrations["Ca"].mg_per_litre() - 190.).abs() < 0.01);
+
+        assert!((concentrations["N"].mg_per_litre() - 169.0).abs() < 0.1);
+
+        assert!((concentrations["K"].meq_per_litre() - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn formula_coefficient() {
+        let mut solution = Solution::new(1.).unwrap();
+
+        solution.add("2KNO3", 1.011).unwrap();
+
+        let potassium = &solution.concentrations()["K"];
+
+        assert!((potassium.mmol_per_litre() - 10.0).abs() < 0.01);
+
+        assert!((potassium.meq_per_litre() - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn formula_coefficient_ions() {
+        let mut solution = Solution::new(1.).unwrap();
+
+        solution.add("2KNO3", 1.011).unwrap();
+
+        let ions = solution.ions();
+
+        assert_eq!(ions.len(), 2);
+
+        ions.iter().for_each(|concentration| {
+            assert!((concentration.mmol_per_litre() - 10.0).abs() < 0.01);
+        });
+
+        assert!((solution.ionic_strength() - 0.01).abs() < 0.0001);
+    }
+
+    #[test]
+    fn impure_reagent() {
+        let mut solution = Solution::new(1.).unwrap();
+
+        solution.add_compound(
+            crate::parse("KNO3").unwrap().with_purity(98.).unwrap(),
+            1.0314,
+        );
+
+        let potassium = &solution.concentrations()["K"];
+
+        assert!((potassium.mmol_per_litre() - 10.0).abs() < 0.01);
+
+        assert!((potassium.meq_per_litre() - 10.0).abs() < 0.01);
+
+        assert!((solution.ions()[0].mmol_per_litre() - 10.0).abs() < 0.01);
+
+        assert!((solution.nitrogen_forms()[&NitrogenForm::Nitrate] - 140.07).abs() < 0.1);
+    }
+
+    #[test]
+    fn non_positive_volume() {
+        assert!(Solution::new(0.).is_err());
+
+        assert!(Solution::new(-1.).is_err());
+    }
+}
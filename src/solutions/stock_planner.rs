@@ -0,0 +1,338 @@
+use crate::solutions::solubility::compound_saturation;
+use crate::solutions::{Saturation, Solution};
+use crate::{Error, Fertiliser};
+
+/// pairs of ions which precipitate in concentrated stock solutions
+const INCOMPATIBLE_IONS: [(&str, &str); 16] = [
+    ("Ca2+", "SO4 2-"),
+    ("Ca2+", "H2PO4-"),
+    ("Ca2+", "HPO4 2-"),
+    ("Ca2+", "PO4 3-"),
+    ("Ca2+", "CO3 2-"),
+    ("Ca2+", "C2O4 2-"),
+    ("Mg2+", "PO4 3-"),
+    ("Fe2+", "H2PO4-"),
+    ("Fe2+", "HPO4 2-"),
+    ("Fe2+", "PO4 3-"),
+    ("Fe3+", "H2PO4-"),
+    ("Fe3+", "HPO4 2-"),
+    ("Fe3+", "PO4 3-"),
+    ("Ba2+", "SO4 2-"),
+    ("Sr2+", "SO4 2-"),
+    ("Pb2+", "SO4 2-"),
+];
+
+/// ions of elements of label-defined products, sulfur and phosphorus
+/// are taken as sulfate and phosphate, iron as either of its ions
+const PRODUCT_IONS: [(&str, &str); 9] = [
+    ("Ca", "Ca2+"),
+    ("Mg", "Mg2+"),
+    ("Fe", "Fe2+"),
+    ("Fe", "Fe3+"),
+    ("Ba", "Ba2+"),
+    ("Sr", "Sr2+"),
+    ("Pb", "Pb2+"),
+    ("S", "SO4 2-"),
+    ("P", "H2PO4-"),
+];
+
+/// A planner of concentrated stock solutions
+///
+/// Splits fertilisers of solution into tanks, so that ions which
+/// precipitate together never share a tank. Each tank is diluted
+/// by concentration factor when solution is prepared
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockPlanner {
+    tanks: usize,
+    factor: f32,
+    temperature: f32,
+}
+
+impl StockPlanner {
+    /// takes positive count of tanks and concentration factor, like 100 for 1:100 stock
+    pub fn new(tanks: usize, factor: f32) -> Result<Self, Error> {
+        if tanks == 0 {
+            return Err(Error::OutOfRange("count of tanks".to_string(), 0.));
+        }
+
+        if factor <= 0.0 || !factor.is_finite() {
+            return Err(Error::OutOfRange(
+                "concentration factor".to_string(),
+                factor,
+            ));
+        }
+
+        Ok(Self {
+            tanks,
+            factor,
+            temperature: 20.,
+        })
+    }
+
+    /// set temperature of stock solutions in °C, 20 °C by default
+    pub fn set_temperature(&mut self, temperature: f32) {
+        self.temperature = temperature;
+    }
+
+    /// split fertilisers of solution into tanks, warning about those exceeding
+    /// solubility, fails when incompatible fertilisers don't fit into tanks
+    pub fn plan(&self, solution: &Solution) -> Result<StockPlan, Error> {
+        let volume = solution.volume() / self.factor;
+
+        let mut tanks: Vec<StockTank> = (0..self.tanks)
+            .map(|_| StockTank {
+                doses: vec![],
+                volume,
+            })
+            .collect();
+
+        let mut doses: Vec<&(Fertiliser, f32)> = solution.doses().iter().collect();
+
+        let conflicts = |fertiliser: &Fertiliser| {
+            solution
+                .doses()
+                .iter()
+                .filter(|(other, _)| incompatible(fertiliser, other))
+                .count()
+        };
+
+        // the most constrained and heaviest fertilisers are placed first
+        doses.sort_by(|a, b| {
+            conflicts(&b.0)
+                .cmp(&conflicts(&a.0))
+                .then(b.1.total_cmp(&a.1))
+        });
+
+        for (fertiliser, mass) in doses {
+            let tank = tanks
+                .iter_mut()
+                .filter(|tank| {
+                    tank.doses
+                        .iter()
+                        .all(|(other, _)| !incompatible(fertiliser, other))
+                })
+                .min_by(|a, b| a.mass().total_cmp(&b.mass()))
+                .ok_or_else(|| Error::Incompatible(name(fertiliser)))?;
+
+            tank.doses.push((fertiliser.clone(), *mass));
+        }
+
+        let mut warnings = vec![];
+
+        tanks.iter().enumerate().for_each(|(index, tank)| {
+            tank.doses.iter().for_each(|(fertiliser, mass)| {
+                let compound = match fertiliser.compound() {
+                    Some(compound) => compound,
+                    None => return,
+                };
+
+                let saturation =
+                    compound_saturation(compound, *mass, tank.volume, self.temperature);
+
+                if let Some(saturation) = saturation.filter(Saturation::is_saturated) {
+                    warnings.push(SolubilityWarning {
+                        formula: name(fertiliser),
+                        tank: index,
+                        saturation,
+                    });
+                }
+            });
+        });
+
+        Ok(StockPlan { tanks, warnings })
+    }
+}
+
+/// A stock solution tank
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockTank {
+    doses: Vec<(Fertiliser, f32)>,
+    volume: f32,
+}
+
+impl StockTank {
+    /// list fertilisers with their masses in grams
+    pub fn doses(&self) -> &[(Fertiliser, f32)] {
+        &self.doses
+    }
+
+    /// get volume of stock solution in litres
+    pub fn volume(&self) -> f32 {
+        self.volume
+    }
+
+    /// get total mass of fertilisers in grams
+    pub fn mass(&self) -> f32 {
+        self.doses.iter().map(|(_, mass)| mass).sum()
+    }
+}
+
+/// A warning about fertiliser exceeding its solubility in stock tank
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolubilityWarning {
+    formula: String,
+    tank: usize,
+    saturation: Saturation,
+}
+
+impl SolubilityWarning {
+    /// get formula of fertiliser
+    pub fn formula(&self) -> &str {
+        &self.formula
+    }
+
+    /// get index of tank
+    pub fn tank(&self) -> usize {
+        self.tank
+    }
+
+    /// get saturation of fertiliser in tank
+    pub fn saturation(&self) -> &Saturation {
+        &self.saturation
+    }
+}
+
+/// A plan of stock solutions
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockPlan {
+    tanks: Vec<StockTank>,
+    warnings: Vec<SolubilityWarning>,
+}
+
+impl StockPlan {
+    /// list tanks, like A and B
+    pub fn tanks(&self) -> &[StockTank] {
+        &self.tanks
+    }
+
+    /// list fertilisers exceeding their solubility
+    pub fn warnings(&self) -> &[SolubilityWarning] {
+        &self.warnings
+    }
+}
+
+fn ions(fertiliser: &Fertiliser) -> Vec<String> {
+    match fertiliser {
+        Fertiliser::Compound(compound) => match compound.dissociate() {
+            Ok(dissociation) => dissociation
+                .ions()
+                .iter()
+                .map(|ion| ion.formula().to_string())
+                .collect(),
+            Err(_) => vec![],
+        },
+
+        Fertiliser::Product(product) => PRODUCT_IONS
+            .iter()
+            .filter(|(symbol, _)| product.analysis().element_percent(symbol) > 0.0)
+            .map(|(_, ion)| ion.to_string())
+            .collect(),
+    }
+}
+
+fn incompatible(a: &Fertiliser, b: &Fertiliser) -> bool {
+    let (a, b) = (ions(a), ions(b));
+
+    INCOMPATIBLE_IONS.iter().any(|(cation, anion)| {
+        let has = |ions: &Vec<String>, ion: &str| ions.iter().any(|own| own == ion);
+
+        (has(&a, cation) && has(&b, anion)) || (has(&a, anion) && has(&b, cation))
+    })
+}
+
+fn name(fertiliser: &Fertiliser) -> String {
+    match fertiliser {
+        Fertiliser::Compound(compound) => compound.substance().to_string(),
+        Fertiliser::Product(product) => product.name().unwrap_or("product").to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StockPlanner;
+    use crate::{Product, Solution};
+
+    fn formulas(tank: &crate::StockTank) -> Vec<String> {
+        tank.doses()
+            .iter()
+            .map(|(fertiliser, _)| fertiliser.compound().unwrap().substance().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn calcium_apart_from_sulfates_and_phosphates() {
+        let mut solution = Solution::new(1000.).unwrap();
+
+        solution.add("Ca(NO3)2*4H2O", 900.).unwrap();
+        solution.add("KNO3", 500.).unwrap();
+        solution.add("MgSO4*7H2O", 500.).unwrap();
+        solution.add("KH2PO4", 150.).unwrap();
+
+        let plan = StockPlanner::new(2, 100.).unwrap().plan(&solution).unwrap();
+
+        let calcium_tank = plan
+            .tanks()
+            .iter()
+            .find(|tank| formulas(tank).contains(&"Ca(NO3)2*4H2O".to_string()))
+            .unwrap();
+
+        assert!(!formulas(calcium_tank).contains(&"MgSO4*7H2O".to_string()));
+
+        assert!(!formulas(calcium_tank).contains(&"KH2PO4".to_string()));
+
+        assert!(plan.warnings().is_empty());
+    }
+
+    #[test]
+    fn solubility_warnings() {
+        let mut solution = Solution::new(1000.).unwrap();
+
+        solution.add("K2SO4", 1500.).unwrap();
+
+        let plan = StockPlanner::new(2, 100.).unwrap().plan(&solution).unwrap();
+
+        assert_eq!(plan.warnings().len(), 1);
+
+        assert_eq!(plan.warnings()[0].formula(), "K2SO4");
+    }
+
+    #[test]
+    fn too_few_tanks() {
+        let mut solution = Solution::new(1000.).unwrap();
+
+        solution.add("Ca(NO3)2", 100.).unwrap();
+        solution.add("MgSO4", 100.).unwrap();
+
+        assert!(StockPlanner::new(1, 100.).unwrap().plan(&solution).is_err());
+
+        assert!(StockPlanner::new(0, 100.).is_err());
+    }
+
+    #[test]
+    fn calcium_product_apart_from_sulfates() {
+        let mut solution = Solution::new(1000.).unwrap();
+
+        solution.add_product(
+            Product::from_label("Calcinit 15.5-0-0 + 19% Ca").unwrap(),
+            900.,
+        );
+        solution.add("MgSO4*7H2O", 500.).unwrap();
+        solution.add("KH2PO4", 150.).unwrap();
+
+        assert!(StockPlanner::new(1, 100.).unwrap().plan(&solution).is_err());
+
+        let plan = StockPlanner::new(2, 100.).unwrap().plan(&solution).unwrap();
+
+        let calcium_tank = plan
+            .tanks()
+            .iter()
+            .find(|tank| {
+                tank.doses()
+                    .iter()
+                    .any(|(fertiliser, _)| fertiliser.compound().is_none())
+            })
+            .unwrap();
+
+        assert_eq!(calcium_tank.doses().len(), 1);
+    }
+}
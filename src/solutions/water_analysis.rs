@@ -0,0 +1,168 @@
+use crate::solutions::{Alkalinity, Hardness, IonBalance, IonConcentration};
+use crate::{Error, Ion};
+use std::collections::HashMap;
+
+/// A mineral analysis of source water
+///
+/// Contains mass concentrations of species in mg/L, like "Ca", "HCO3"
+/// or "SO4 2-". Species written without charge take the charge of
+/// common ion, others stay neutral, like "SiO2"
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WaterAnalysis {
+    species: Vec<(Ion, f32)>,
+}
+
+impl WaterAnalysis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// parse lines of "species = mg/L" or "species: mg/L", "#" starts comment
+    pub fn from_key_values(text: &str) -> Result<Self, Error> {
+        let mut analysis = Self::new();
+
+        for line in lines(text) {
+            let (species, value) = line
+                .split_once(['=', ':'])
+                .ok_or(Error::InvalidAnalysis(line.to_string()))?;
+
+            analysis.add(species.trim(), value_of(line, value)?)?;
+        }
+
+        Ok(analysis)
+    }
+
+    /// parse rows of species and mg/L separated by comma or semicolon,
+    /// first row may be header, columns after second are ignored
+    pub fn from_csv(text: &str) -> Result<Self, Error> {
+        let mut analysis = Self::new();
+
+        for (index, line) in lines(text).enumerate() {
+            let mut columns = line.split([',', ';']).map(str::trim);
+
+            let species = columns.next().unwrap_or("");
+
+            let value = columns.next().unwrap_or("");
+
+            match value.parse::<f32>() {
+                Ok(value) => analysis.add(species, value)?,
+                Err(_) if index == 0 => continue,
+                Err(_) => return Err(Error::InvalidAnalysis(line.to_string())),
+            }
+        }
+
+        Ok(analysis)
+    }
+
+    /// add concentration of species in mg/L
+    pub fn add(&mut self, species: &str, mg_per_litre: f32) -> Result<(), Error> {
+        let ion = crate::ions::species(crate::parse_substance(species)?);
+
+        match self
+            .species
+            .iter_mut()
+            .find(|(added, _)| added.formula() == ion.formula())
+        {
+            Some((_, added)) => *added += mg_per_litre,
+            None => self.species.push((ion, mg_per_litre)),
+        }
+
+        Ok(())
+    }
+
+    /// list species with concentrations in mg/L
+    pub fn species(&self) -> &[(Ion, f32)] {
+        &self.species
+    }
+
+    /// list concentrations of charged species
+    pub fn ions(&self) -> Vec<IonConcentration> {
+        self.species
+            .iter()
+            .filter(|(ion, _)| ion.charge() != 0)
+            .map(|(ion, mg_per_litre)| {
+                let mut concentration = IonConcentration::new(ion.clone());
+
+                concentration.add_moles(mg_per_litre / ion.compound().molar_mass());
+
+                concentration
+            })
+            .collect()
+    }
+
+    /// get balance of cations and anions
+    pub fn ion_balance(&self) -> IonBalance {
+        IonBalance::new(&self.ions())
+    }
+
+    /// get calcium and magnesium hardness
+    pub fn hardness(&self) -> Hardness {
+        Hardness::new(&self.ions())
+    }
+
+    /// get alkalinity of bicarbonate, carbonate and hydroxide
+    pub fn alkalinity(&self) -> Alkalinity {
+        Alkalinity::new(&self.ions())
+    }
+
+    /// list mass concentrations of elements in mg/L
+    pub fn elements(&self) -> HashMap<&'static str, f32> {
+        let mut elements = HashMap::new();
+
+        self.species.iter().for_each(|(ion, mg_per_litre)| {
+            ion.compound()
+                .components()
+                .iter()
+                .for_each(|(symbol, component)| {
+                    *elements.entry(*symbol).or_insert(0.0) +=
+                        mg_per_litre * component.mass_percent() / 100.;
+                });
+        });
+
+        elements
+    }
+}
+
+fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or("").trim())
+        .filter(|line| !line.is_empty())
+}
+
+fn value_of(line: &str, value: &str) -> Result<f32, Error> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| Error::InvalidAnalysis(line.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WaterAnalysis;
+
+    #[test]
+    fn key_values_and_csv() {
+        let analysis = WaterAnalysis::from_key_values(
+            "# tap water\nCa = 40\nMg: 12\nHCO3 = 183\nSO4 2- = 48\nSiO2 = 10",
+        )
+        .unwrap();
+
+        let formulas: Vec<&str> = analysis
+            .species()
+            .iter()
+            .map(|(ion, _)| ion.formula())
+            .collect();
+
+        assert_eq!(formulas, ["Ca2+", "Mg2+", "HCO3-", "SO4 2-", "SiO2"]);
+
+        assert!((analysis.elements()["S"] - 16.02).abs() < 0.01);
+
+        let csv = WaterAnalysis::from_csv("species,mg/L\nCa,40\nMg;12\nHCO3,183,mg/L").unwrap();
+
+        assert_eq!(csv.species().len(), 3);
+
+        assert!(WaterAnalysis::from_csv("Ca,40\nMg,high").is_err());
+
+        assert!(WaterAnalysis::from_key_values("Ca 40").is_err());
+    }
+}
@@ -7,6 +7,10 @@ pub enum Token<'a> {
     LParen,
     RParen,
     Asterisk,
+    Caret,
+    Space,
+    Plus,
+    Minus,
 }
 
 impl<'a> Token<'a> {
@@ -17,6 +21,10 @@ impl<'a> Token<'a> {
             Self::LParen => "(",
             Self::RParen => ")",
             Self::Asterisk => "*",
+            Self::Caret => "^",
+            Self::Space => " ",
+            Self::Plus => "+",
+            Self::Minus => "-",
         }
     }
 }
@@ -83,9 +91,9 @@ impl<'a> Tokenizer<'a> {
 
             self.cursor += symbol.len();
 
-            return Some(Token::Symbol(symbol));
+            Some(Token::Symbol(symbol))
         } else {
-            return None;
+            None
         }
     }
 
@@ -93,7 +101,7 @@ impl<'a> Tokenizer<'a> {
         let mut length = 0;
 
         while let Some(char) = self.chars.get(self.cursor + length) {
-            if char.is_digit(10) {
+            if char.is_ascii_digit() {
                 length += 1;
                 continue;
             } else {
@@ -106,9 +114,9 @@ impl<'a> Tokenizer<'a> {
 
             self.cursor += number.len();
 
-            return Some(Token::Number(number));
+            Some(Token::Number(number))
         } else {
-            return None;
+            None
         }
     }
 
@@ -119,6 +127,10 @@ impl<'a> Tokenizer<'a> {
                     '(' => Some(Token::LParen),
                     ')' => Some(Token::RParen),
                     '*' => Some(Token::Asterisk),
+                    '^' => Some(Token::Caret),
+                    ' ' => Some(Token::Space),
+                    '+' => Some(Token::Plus),
+                    '-' => Some(Token::Minus),
                     _ => None,
                 };
 
@@ -1,7 +1,16 @@
 use crate::tokens::{Element, Group};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Component {
     Element(Element),
     Group(Group),
 }
+
+impl std::fmt::Display for Component {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Element(element) => write!(f, "{}", element),
+            Self::Group(group) => write!(f, "{}", group),
+        }
+    }
+}
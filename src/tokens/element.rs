@@ -37,6 +37,18 @@ impl Element {
     }
 }
 
+impl std::fmt::Display for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.chemical_element.symbol())?;
+
+        if self.subscript > 1 {
+            write!(f, "{}", self.subscript)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Element;
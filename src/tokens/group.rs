@@ -1,6 +1,6 @@
 use crate::tokens::{Component, Element};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Group {
     composition: Vec<Component>,
     subscript: usize,
@@ -30,7 +30,15 @@ impl Group {
         self.subscript = subscript;
     }
 
-    pub(crate) fn elements(&self) -> Vec<Element> {
+    pub fn composition(&self) -> &[Component] {
+        &self.composition
+    }
+
+    pub fn subscript(&self) -> usize {
+        self.subscript
+    }
+
+    pub fn elements(&self) -> Vec<Element> {
         let mut elements = vec![];
 
         self.composition
@@ -51,6 +59,24 @@ impl Group {
     }
 }
 
+impl std::fmt::Display for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "(")?;
+
+        for component in &self.composition {
+            write!(f, "{}", component)?;
+        }
+
+        write!(f, ")")?;
+
+        if self.subscript > 1 {
+            write!(f, "{}", self.subscript)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tokens::{Component, Element, Group};
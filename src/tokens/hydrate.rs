@@ -1,7 +1,7 @@
 use crate::chemistry::ChemicalElement;
 use crate::tokens::Element;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Hydrate {
     coefficient: usize,
     elements: Vec<Element>,
@@ -18,7 +18,6 @@ impl Hydrate {
         }
     }
 
-    #[allow(unused)]
     pub(crate) fn from(coefficient: usize) -> Self {
         Self {
             coefficient,
@@ -33,6 +32,10 @@ impl Hydrate {
         self.coefficient = coefficient;
     }
 
+    pub fn coefficient(&self) -> usize {
+        self.coefficient
+    }
+
     pub fn elements(&self) -> Vec<Element> {
         self.elements
             .iter()
@@ -41,6 +44,15 @@ impl Hydrate {
     }
 }
 
+impl std::fmt::Display for Hydrate {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.coefficient {
+            1 => write!(f, "*H2O"),
+            coefficient => write!(f, "*{}H2O", coefficient),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tokens::{Element, Hydrate};
@@ -1,3 +1,5 @@
+//! Parsed tree of formula: substance, groups, elements and hydrate
+
 mod component;
 mod element;
 mod group;
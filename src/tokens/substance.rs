@@ -1,10 +1,11 @@
 use crate::tokens::{Component, Element, Hydrate};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Substance {
     coefficient: usize,
     composition: Vec<Component>,
     hydrate: Option<Hydrate>,
+    charge: i32,
 }
 
 impl Substance {
@@ -13,6 +14,7 @@ impl Substance {
             coefficient: 1,
             composition: vec![],
             hydrate: None,
+            charge: 0,
         }
     }
 
@@ -26,6 +28,7 @@ impl Substance {
             coefficient,
             composition,
             hydrate,
+            charge: 0,
         }
     }
 
@@ -41,7 +44,44 @@ impl Substance {
         self.hydrate = Some(hydrate);
     }
 
-    pub(crate) fn elements(&self) -> Vec<Element> {
+    pub(crate) fn add_charge(&mut self, charge: i32) {
+        self.charge = charge;
+    }
+
+    pub(crate) fn with_charge(mut self, charge: i32) -> Self {
+        self.charge = charge;
+        self
+    }
+
+    /// substance with count of water molecules of crystallisation changed,
+    /// 0 strips water
+    pub fn with_water(&self, water: usize) -> Substance {
+        Self {
+            hydrate: match water {
+                0 => None,
+                water => Some(Hydrate::from(water)),
+            },
+            ..self.clone()
+        }
+    }
+
+    pub fn coefficient(&self) -> usize {
+        self.coefficient
+    }
+
+    pub fn hydrate(&self) -> Option<&Hydrate> {
+        self.hydrate.as_ref()
+    }
+
+    pub fn composition(&self) -> &[Component] {
+        &self.composition
+    }
+
+    pub fn charge(&self) -> i32 {
+        self.charge * self.coefficient as i32
+    }
+
+    pub fn elements(&self) -> Vec<Element> {
         let mut elements: Vec<Element> = Vec::new();
 
         self.composition
@@ -68,6 +108,42 @@ impl Substance {
     }
 }
 
+impl std::fmt::Display for Substance {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.coefficient > 1 {
+            write!(f, "{}", self.coefficient)?;
+        }
+
+        for component in &self.composition {
+            write!(f, "{}", component)?;
+        }
+
+        if self.charge != 0 {
+            let sign = if self.charge > 0 { "+" } else { "-" };
+
+            let magnitude = self.charge.unsigned_abs();
+
+            // keeps charge distinguishable from subscript of single element
+            match self.composition.as_slice() {
+                [Component::Element(element)] if element.subscript() == 1 && magnitude > 1 => {
+                    write!(f, "{}{}", magnitude, sign)?
+                }
+                [Component::Element(element)] if element.subscript() > 1 && magnitude == 1 => {
+                    write!(f, "^{}", sign)?
+                }
+                _ if magnitude > 1 => write!(f, " {}{}", magnitude, sign)?,
+                _ => write!(f, "{}", sign)?,
+            }
+        }
+
+        if let Some(hydrate) = &self.hydrate {
+            write!(f, "{}", hydrate)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tokens::{Component, Element, Hydrate, Substance};
@@ -95,4 +171,25 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn display() {
+        let table = crate::chemistry::Table::new();
+
+        [
+            "Ca(NO3)2*4H2O",
+            "2KNO3",
+            "Ca2+",
+            "PO4 3-",
+            "NO3-",
+            "O2^-",
+            "Fe",
+        ]
+        .iter()
+        .for_each(|formula| {
+            let substance = crate::parser::Parser::new(&table, formula).parse().unwrap();
+
+            assert_eq!(substance.to_string(), *formula);
+        });
+    }
 }